@@ -0,0 +1,86 @@
+//! Generates a test for every sample case in `input/`, see `src/samples.rs`
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The year of the case files directly in `input/`, like `DEFAULT_YEAR` in `src/lib.rs`
+const DEFAULT_YEAR: u16 = 2022;
+
+fn main() {
+    println!("cargo:rerun-if-changed=input");
+    let mut files = case_files(Path::new("input"), DEFAULT_YEAR);
+    for entry in fs::read_dir("input").into_iter().flatten().flatten() {
+        let year = entry.file_name().to_string_lossy().parse::<u16>();
+        if let (Ok(year), true) = (year, entry.path().is_dir()) {
+            files.extend(case_files(&entry.path(), year));
+        }
+    }
+    files.sort();
+
+    let mut code = String::new();
+    for (year, day, path) in files {
+        let text = fs::read_to_string(&path).expect("could not read a sample case file");
+        let module = if year == DEFAULT_YEAR {
+            format!("day{:02}", day)
+        } else {
+            format!("y{}_day{:02}", year, day)
+        };
+        writeln!(code, "mod {} {{", module).unwrap();
+        for name in text.lines().filter_map(|line| line.strip_prefix("== ")) {
+            let name = name.trim();
+            writeln!(
+                code,
+                "    #[test]\n    fn {}() {{\n        super::check_case({}, {}, {:?});\n    }}",
+                test_name(name),
+                year,
+                day,
+                name
+            )
+            .unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+    }
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("sample_cases.rs"), code).unwrap();
+}
+
+/// The `dayXX_cases.txt` files in `dir`, with their year and day
+fn case_files(dir: &Path, year: u16) -> Vec<(u16, u8, PathBuf)> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let day = name
+            .strip_prefix("day")
+            .and_then(|rest| rest.strip_suffix("_cases.txt"))
+            .and_then(|day| day.parse().ok());
+        if let Some(day) = day {
+            files.push((year, day, entry.path()));
+        }
+    }
+    files
+}
+
+/// Turn a case name into a function name. Names that would not be valid get a `case_` prefix.
+fn test_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '_' => c,
+            'A'..='Z' => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect();
+    let keywords = [
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+        "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+        "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+        "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type",
+        "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    if name.starts_with(|c: char| c.is_ascii_lowercase()) && !keywords.contains(&name.as_str()) {
+        name
+    } else {
+        format!("case_{}", name)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::runner::{Input, Part};
+use crate::{Answer, Solution};
+use anyhow::{ensure, Result};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How many times to run each step of a benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs that are done before measuring, and thrown away
+    pub warmup: usize,
+    /// Runs that are measured
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 10,
+            runs: 100,
+        }
+    }
+}
+
+/// Summary of the wall time of a number of runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarize `samples`, which must not be empty
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+        }
+    }
+}
+
+/// The nearest-rank percentile of sorted `samples`
+fn percentile(samples: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * samples.len()).div_ceil(100);
+    samples[rank.saturating_sub(1)]
+}
+
+/// Benchmark results for one part of a day
+#[derive(Debug)]
+pub struct PartBench {
+    pub part: Part,
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Benchmark results for a whole day
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: u8,
+    pub title: &'static str,
+    pub config: BenchConfig,
+    /// Reading the input file into lines. Stdin can only be read once, so it isn't timed.
+    pub read: Option<Stats>,
+    pub parts: Vec<PartBench>,
+}
+
+/// Run `f` `config.warmup` times, and then time it `config.runs` times
+fn measure<T>(config: BenchConfig, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Benchmark reading `input`, and parsing and solving each of `parts`
+pub fn bench_day(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: &Input,
+    config: BenchConfig,
+) -> Result<DayBench> {
+    ensure!(config.runs > 0, "Need at least one run to benchmark");
+    let lines = input.read(solution)?;
+    let read = match input {
+        Input::Stdin => None,
+        Input::Default | Input::File(_) => Some(measure(config, || input.read(solution))?),
+    };
+
+    let mut part_benches = Vec::new();
+    for &part in parts {
+        let parse = measure(config, || solution.prepare(part, &lines))?;
+        let solver = solution.prepare(part, &lines)?;
+        let solve = measure(config, &solver)?;
+        part_benches.push(PartBench {
+            part,
+            answer: solver()?,
+            parse,
+            solve,
+        });
+    }
+    Ok(DayBench {
+        day: solution.day(),
+        title: solution.title(),
+        config,
+        read,
+        parts: part_benches,
+    })
+}
+
+/// Format `duration` with a unit that fits its size, e.g. `12.34µs`
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {:02}: {} ({} runs after {} warmup)",
+            self.day, self.title, self.config.runs, self.config.warmup
+        )?;
+        writeln!(f, "{:<14}{:>12}{:>12}{:>12}", "", "min", "median", "p95")?;
+        if let Some(read) = &self.read {
+            write_stats(f, "read", read)?;
+        }
+        for part in &self.parts {
+            write_stats(f, &format!("part {} parse", part.part), &part.parse)?;
+            write_stats(f, &format!("part {} solve", part.part), &part.solve)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_stats(f: &mut fmt::Formatter<'_>, label: &str, stats: &Stats) -> fmt::Result {
+    writeln!(
+        f,
+        "{:<14}{:>12}{:>12}{:>12}",
+        label,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.p95)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::get_solution;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn stats_from_single_sample() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!(stats.min, stats.p95);
+        assert_eq!(stats.median, stats.p95);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_000)), "2.00s");
+    }
+
+    #[test]
+    fn bench_day_on_sample() {
+        let solution = get_solution(2022, 9).unwrap();
+        let input = Input::File("input/day09_sample.txt".into());
+        let config = BenchConfig { warmup: 1, runs: 3 };
+        let result = bench_day(solution, &Part::BOTH, &input, config).unwrap();
+        assert!(result.read.is_some());
+        assert!(result.to_string().contains("\nread "));
+        let answers: Vec<Answer> = result.parts.into_iter().map(|p| p.answer).collect();
+        assert_eq!(answers, vec![Answer::Int(13), Answer::Int(1)]);
+    }
+}
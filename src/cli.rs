@@ -0,0 +1,806 @@
+use crate::bench::BenchConfig;
+use crate::export::ExportConfig;
+use crate::report::Format;
+use crate::runner::{Input, Part};
+use crate::verify::ANSWERS_FILE;
+use crate::visualize::PlayConfig;
+use crate::DEFAULT_YEAR;
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run [year] <day> [--part 1|2] [--input PATH|-] [--stream] [--format text|json|csv]
+    aoc run [year] <day> --visualize [--part 1|2] [--input PATH] [--delay MS] [--step]
+    aoc run [year] <day> --export DIR [--part 1|2] [--input PATH|-] [--scale N] [--palette P]
+            [--image png|ppm] [--last]
+    aoc all [year] [--jobs N] [--format text|json|csv]
+    aoc bench [year] <day> [--part 1|2] [--input PATH|-] [--runs N] [--warmup N]
+    aoc verify [--answers PATH]
+    aoc fetch [year] <day> [--force]
+    aoc submit [year] <day> <part>
+    aoc watch [year] <day>
+    aoc samples [year] <day> <PAGE> [--force]
+    aoc generate [year] <day> [--size N] [--seed N]
+    aoc fuzz [year] <day> [--runs N] [--seed N]
+    aoc new [year] <day> [--title TITLE]
+    aoc help
+
+The year is 2022 when it is left out.
+
+Commands:
+    run     Run a single day. Use --input - to read the input from stdin, and --stream to
+            read it one line at a time on days that support it. --format json or csv writes
+            the answers and timings for other tools. --visualize shows days 5, 8, 9 and 10 step
+            by step, with --delay MS between frames (100 by default), or paused with --step.
+            --export DIR saves the same frames as images instead, with a square of N pixels per
+            character (4 by default). The palette is dark, light or five hex colours, and --last
+            only saves the last frame
+    all     Run every solved day in order, or only those of a year. --jobs N runs up to N parts at
+            the same time, but still prints them in order
+    bench   Time reading, parsing and solving a day. Build with --release for real numbers
+    verify  Check every day against the accepted answers in answers.txt
+    fetch   Download the puzzle input to input/<year>/dayXX.txt, unless it is already there. Needs
+            a session token in AOC_SESSION or aoc.conf
+    submit  Solve a part on the real input and submit the answer. Answers that are already
+            known to be wrong are not submitted, and neither is anything during the cooldown
+    watch   Run the sample tests and the real input again every time the input, samples or
+            source of a day change
+    samples Write the sample inputs and their answers from a puzzle page saved from the browser to
+            input/dayXX_sample.txt and input/dayXX_cases.txt, for review before committing them
+    generate
+            Write a random input that follows the rules of the puzzle to stdout, of about N lines
+            (1000 by default). The seed is printed to stderr, so the same input can be made again
+    fuzz    Run a day on N mangled versions of its samples and generated inputs (10000 by
+            default), and save the inputs that make it panic to fuzz/findings/
+    new     Create a new day from template.rs, with an empty sample input and a case for it
+    help    Show this message";
+
+/// A parsed command line
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run this part, or both when `None`
+        part: Option<Part>,
+        input: Input,
+        /// Read the input one line at a time, see [`crate::Solution::stream`]
+        stream: bool,
+        format: Format,
+        /// Show the simulation in the terminal instead of just the answer
+        visualize: Option<PlayConfig>,
+        /// Save the frames of the simulation as images instead of just the answer
+        export: Option<ExportConfig>,
+    },
+    All {
+        /// Only run this year, or every year when `None`
+        year: Option<u16>,
+        /// How many parts to run at the same time
+        jobs: usize,
+        format: Format,
+    },
+    Bench {
+        year: u16,
+        day: u8,
+        part: Option<Part>,
+        input: Input,
+        config: BenchConfig,
+    },
+    Verify {
+        /// The file with accepted answers
+        answers: String,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Download again even if the input file exists
+        force: bool,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+    },
+    Watch {
+        year: u16,
+        day: u8,
+    },
+    Samples {
+        year: u16,
+        day: u8,
+        /// The saved puzzle page
+        page: PathBuf,
+        /// Replace sample files that already exist
+        force: bool,
+    },
+    Generate {
+        year: u16,
+        day: u8,
+        size: usize,
+        /// A random seed is picked when `None`
+        seed: Option<u64>,
+    },
+    Fuzz {
+        year: u16,
+        day: u8,
+        runs: usize,
+        /// A random seed is picked when `None`
+        seed: Option<u64>,
+    },
+    New {
+        year: u16,
+        day: u8,
+        title: Option<String>,
+    },
+    Help,
+}
+
+/// Parse the command line arguments, not including the program name
+pub fn parse_args<I>(args: I) -> Result<Command>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Ok(Command::Help),
+    };
+    match command.as_str() {
+        "run" => {
+            let (year, day, options) = parse_day_options(args)?;
+            if options.bench_config.is_some() {
+                bail!("--runs and --warmup can only be used with bench");
+            }
+            if options.stream && options.input == Input::Stdin && options.part.is_none() {
+                bail!("stdin can only be streamed once, so choose a part with --part");
+            }
+            if options.play_config.is_some() && !options.visualize {
+                bail!("--delay and --step can only be used with --visualize");
+            }
+            if options.export_config.is_some() && options.export.is_none() {
+                bail!("--scale, --palette, --image and --last can only be used with --export");
+            }
+            if options.visualize && options.export.is_some() {
+                bail!("--visualize and --export can not be used together");
+            }
+            if (options.visualize || options.export.is_some())
+                && (options.stream || options.format.is_some())
+            {
+                bail!("--visualize and --export can not be used with --stream or --format");
+            }
+            if options.visualize && options.input == Input::Stdin {
+                bail!("--visualize reads keys from stdin, so the input must be a file");
+            }
+            Ok(Command::Run {
+                year,
+                day,
+                part: options.part,
+                input: options.input,
+                stream: options.stream,
+                format: options.format.unwrap_or_default(),
+                visualize: options
+                    .visualize
+                    .then(|| options.play_config.unwrap_or_default()),
+                export: options.export.map(|dir| ExportConfig {
+                    dir,
+                    ..options.export_config.unwrap_or_default()
+                }),
+            })
+        }
+        "bench" => {
+            let (year, day, options) = parse_day_options(args)?;
+            if options.stream {
+                bail!("--stream can only be used with run");
+            }
+            if options.visualize || options.play_config.is_some() {
+                bail!("--visualize can only be used with run");
+            }
+            if options.export.is_some() || options.export_config.is_some() {
+                bail!("--export can only be used with run");
+            }
+            if options.format.is_some() {
+                bail!("--format can only be used with run and all");
+            }
+            Ok(Command::Bench {
+                year,
+                day,
+                part: options.part,
+                input: options.input,
+                config: options.bench_config.unwrap_or_default(),
+            })
+        }
+        "all" => {
+            let mut year = None;
+            let mut jobs = 1;
+            let mut format = Format::default();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--jobs" | "-j" => jobs = parse_count(&arg, args.next())?,
+                    "--format" | "-f" => format = value_for(&arg, args.next())?.parse()?,
+                    _ if year.is_none() => year = Some(parse_year(&arg)?),
+                    _ => bail!("Unexpected argument '{}'", arg),
+                }
+            }
+            if jobs == 0 {
+                bail!("--jobs must be at least 1");
+            }
+            Ok(Command::All { year, jobs, format })
+        }
+        "verify" => {
+            let mut answers = ANSWERS_FILE.to_owned();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--answers" => answers = value_for(&arg, args.next())?,
+                    _ => bail!("Unexpected argument '{}'", arg),
+                }
+            }
+            Ok(Command::Verify { answers })
+        }
+        "fetch" => {
+            let mut positional = Vec::new();
+            let mut force = false;
+            for arg in args {
+                match arg.as_str() {
+                    "--force" => force = true,
+                    _ => positional.push(arg),
+                }
+            }
+            let (year, day) = parse_puzzle(&positional, "fetch")?;
+            Ok(Command::Fetch { year, day, force })
+        }
+        "submit" => {
+            let mut positional: Vec<String> = args.collect();
+            if positional.len() < 2 {
+                bail!("Missing the day and part to submit");
+            }
+            let part = positional.pop().unwrap_or_default().parse()?;
+            let (year, day) = parse_puzzle(&positional, "submit")?;
+            Ok(Command::Submit { year, day, part })
+        }
+        "watch" => {
+            let positional: Vec<String> = args.collect();
+            let (year, day) = parse_puzzle(&positional, "watch")?;
+            Ok(Command::Watch { year, day })
+        }
+        "samples" => {
+            let mut positional = Vec::new();
+            let mut force = false;
+            for arg in args {
+                match arg.as_str() {
+                    "--force" => force = true,
+                    _ => positional.push(arg),
+                }
+            }
+            let page = positional
+                .pop()
+                .context("Missing the day and the saved page")?;
+            let (year, day) = parse_puzzle(&positional, "take samples for")?;
+            Ok(Command::Samples {
+                year,
+                day,
+                page: page.into(),
+                force,
+            })
+        }
+        "generate" => {
+            let mut positional = Vec::new();
+            let mut size = 1000;
+            let mut seed = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--size" => size = parse_count(&arg, args.next())?,
+                    "--seed" => seed = Some(parse_count(&arg, args.next())? as u64),
+                    _ => positional.push(arg),
+                }
+            }
+            let (year, day) = parse_puzzle(&positional, "generate")?;
+            Ok(Command::Generate {
+                year,
+                day,
+                size,
+                seed,
+            })
+        }
+        "fuzz" => {
+            let mut positional = Vec::new();
+            let mut runs = 10_000;
+            let mut seed = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--runs" => runs = parse_count(&arg, args.next())?,
+                    "--seed" => seed = Some(parse_count(&arg, args.next())? as u64),
+                    _ => positional.push(arg),
+                }
+            }
+            let (year, day) = parse_puzzle(&positional, "fuzz")?;
+            Ok(Command::Fuzz {
+                year,
+                day,
+                runs,
+                seed,
+            })
+        }
+        "new" => {
+            let mut positional = Vec::new();
+            let mut title = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--title" => title = Some(value_for(&arg, args.next())?),
+                    _ => positional.push(arg),
+                }
+            }
+            let (year, day) = parse_puzzle(&positional, "create")?;
+            Ok(Command::New { year, day, title })
+        }
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => bail!("Unknown command '{}'", command),
+    }
+}
+
+/// Options shared by the commands that work on a single day
+struct DayOptions {
+    part: Option<Part>,
+    input: Input,
+    /// Only set if --runs or --warmup was given
+    bench_config: Option<BenchConfig>,
+    stream: bool,
+    /// Only set if --format was given
+    format: Option<Format>,
+    visualize: bool,
+    /// Only set if --delay or --step was given
+    play_config: Option<PlayConfig>,
+    /// The directory to save images to
+    export: Option<PathBuf>,
+    /// Only set if --scale, --palette, --image or --last was given
+    export_config: Option<ExportConfig>,
+}
+
+fn parse_day_options(mut args: impl Iterator<Item = String>) -> Result<(u16, u8, DayOptions)> {
+    let mut positional = Vec::new();
+    let mut options = DayOptions {
+        part: None,
+        input: Input::Default,
+        bench_config: None,
+        stream: false,
+        format: None,
+        visualize: false,
+        play_config: None,
+        export: None,
+        export_config: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => options.part = Some(value_for(&arg, args.next())?.parse()?),
+            "--input" | "-i" => {
+                options.input = match value_for(&arg, args.next())?.as_str() {
+                    "-" => Input::Stdin,
+                    path => Input::File(path.into()),
+                }
+            }
+            "--stream" => options.stream = true,
+            "--visualize" => options.visualize = true,
+            "--delay" => {
+                let config = options.play_config.get_or_insert_with(Default::default);
+                config.delay = Duration::from_millis(parse_count(&arg, args.next())? as u64);
+            }
+            "--step" => {
+                let config = options.play_config.get_or_insert_with(Default::default);
+                config.paused = true;
+            }
+            "--export" => options.export = Some(value_for(&arg, args.next())?.into()),
+            "--scale" => {
+                let config = options.export_config.get_or_insert_with(Default::default);
+                config.scale = parse_count(&arg, args.next())?;
+                if config.scale == 0 {
+                    bail!("--scale must be at least 1");
+                }
+            }
+            "--palette" => {
+                let config = options.export_config.get_or_insert_with(Default::default);
+                config.palette = value_for(&arg, args.next())?.parse()?;
+            }
+            "--image" => {
+                let config = options.export_config.get_or_insert_with(Default::default);
+                config.format = value_for(&arg, args.next())?.parse()?;
+            }
+            "--last" => {
+                let config = options.export_config.get_or_insert_with(Default::default);
+                config.only_last = true;
+            }
+            "--format" | "-f" => options.format = Some(value_for(&arg, args.next())?.parse()?),
+            "--runs" => {
+                let config = options.bench_config.get_or_insert_with(Default::default);
+                config.runs = parse_count(&arg, args.next())?;
+            }
+            "--warmup" => {
+                let config = options.bench_config.get_or_insert_with(Default::default);
+                config.warmup = parse_count(&arg, args.next())?;
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let (year, day) = parse_puzzle(&positional, "run")?;
+    Ok((year, day, options))
+}
+
+/// The year and day from arguments like `2022 1`, or just `1` for [`DEFAULT_YEAR`]
+fn parse_puzzle(positional: &[String], action: &str) -> Result<(u16, u8)> {
+    match positional {
+        [] => bail!("Missing the day to {}", action),
+        [day] => Ok((DEFAULT_YEAR, parse_day(day)?)),
+        [year, day] => Ok((parse_year(year)?, parse_day(day)?)),
+        [_, _, extra, ..] => bail!("Unexpected argument '{}'", extra),
+    }
+}
+
+fn parse_count(flag: &str, value: Option<String>) -> Result<usize> {
+    let value = value_for(flag, value)?;
+    value
+        .parse()
+        .with_context(|| format!("'{}' is not a valid count for {}", value, flag))
+}
+
+fn value_for(flag: &str, value: Option<String>) -> Result<String> {
+    value.with_context(|| format!("Missing a value for {}", flag))
+}
+
+fn parse_year(arg: &str) -> Result<u16> {
+    match arg.parse() {
+        // The first Advent of Code was in 2015
+        Ok(year @ 2015..=9999) => Ok(year),
+        _ => bail!("'{}' is not a valid year, expected 2015 or later", arg),
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8> {
+    match arg.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => bail!("'{}' is not a valid day, expected 1 to 25", arg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::{ImageFormat, Palette};
+
+    fn parse(args: &[&str]) -> Result<Command> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_run_with_defaults() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 9,
+            part: None,
+            input: Input::Default,
+            stream: false,
+            format: Format::Text,
+            visualize: None,
+            export: None,
+        };
+        assert_eq!(parse(&["run", "9"]).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_run_with_part_and_input() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 5,
+            part: Some(Part::Two),
+            input: Input::File("input/day05_sample.txt".into()),
+            stream: false,
+            format: Format::Text,
+            visualize: None,
+            export: None,
+        };
+        let actual = parse(&[
+            "run",
+            "5",
+            "--part",
+            "2",
+            "--input",
+            "input/day05_sample.txt",
+        ]);
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_run_with_year() {
+        let expected = Command::Run {
+            year: 2021,
+            day: 3,
+            part: Some(Part::One),
+            input: Input::Default,
+            stream: false,
+            format: Format::Text,
+            visualize: None,
+            export: None,
+        };
+        assert_eq!(parse(&["run", "2021", "3", "-p", "1"]).unwrap(), expected);
+        assert!(parse(&["run", "2014", "3"]).is_err());
+        assert!(parse(&["run", "2021", "3", "4"]).is_err());
+    }
+
+    #[test]
+    fn parse_run_from_stdin() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 1,
+            part: None,
+            input: Input::Stdin,
+            stream: false,
+            format: Format::Text,
+            visualize: None,
+            export: None,
+        };
+        assert_eq!(parse(&["run", "--input", "-", "1"]).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_run_streaming() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 1,
+            part: Some(Part::One),
+            input: Input::Stdin,
+            stream: true,
+            format: Format::Text,
+            visualize: None,
+            export: None,
+        };
+        let actual = parse(&["run", "1", "--stream", "-i", "-", "-p", "1"]);
+        assert_eq!(actual.unwrap(), expected);
+        assert!(parse(&["run", "1", "--stream", "-i", "-"]).is_err());
+    }
+
+    #[test]
+    fn parse_run_visualize() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 9,
+            part: Some(Part::Two),
+            input: Input::Default,
+            stream: false,
+            format: Format::Text,
+            visualize: Some(PlayConfig {
+                delay: Duration::from_millis(20),
+                paused: true,
+            }),
+            export: None,
+        };
+        let actual = parse(&[
+            "run",
+            "9",
+            "-p",
+            "2",
+            "--visualize",
+            "--delay",
+            "20",
+            "--step",
+        ]);
+        assert_eq!(actual.unwrap(), expected);
+        let actual = parse(&["run", "9", "--visualize"]).unwrap();
+        assert!(
+            matches!(actual, Command::Run { visualize: Some(config), .. } if config == PlayConfig::default())
+        );
+        assert!(parse(&["run", "9", "--delay", "20"]).is_err());
+        assert!(parse(&["run", "9", "--visualize", "-i", "-"]).is_err());
+        assert!(parse(&["run", "9", "--visualize", "--stream"]).is_err());
+        assert!(parse(&["bench", "9", "--visualize"]).is_err());
+    }
+
+    #[test]
+    fn parse_run_export() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 10,
+            part: None,
+            input: Input::Stdin,
+            stream: false,
+            format: Format::Text,
+            visualize: None,
+            export: Some(ExportConfig {
+                dir: "slides".into(),
+                format: ImageFormat::Ppm,
+                scale: 8,
+                palette: Palette::LIGHT,
+                only_last: true,
+            }),
+        };
+        let actual = parse(&[
+            "run",
+            "10",
+            "-i",
+            "-",
+            "--export",
+            "slides",
+            "--scale",
+            "8",
+            "--palette",
+            "light",
+            "--image",
+            "ppm",
+            "--last",
+        ]);
+        assert_eq!(actual.unwrap(), expected);
+        assert!(parse(&["run", "10", "--scale", "8"]).is_err());
+        assert!(parse(&["run", "10", "--export", "slides", "--scale", "0"]).is_err());
+        assert!(parse(&["run", "10", "--export", "slides", "--palette", "pink"]).is_err());
+        assert!(parse(&["run", "10", "--export", "slides", "--visualize"]).is_err());
+        assert!(parse(&["bench", "10", "--export", "slides"]).is_err());
+    }
+
+    #[test]
+    fn parse_all() {
+        let expected = Command::All {
+            year: None,
+            jobs: 1,
+            format: Format::Text,
+        };
+        assert_eq!(parse(&["all"]).unwrap(), expected);
+        let expected = Command::All {
+            year: Some(2022),
+            jobs: 4,
+            format: Format::Csv,
+        };
+        let actual = parse(&["all", "2022", "--format", "csv", "--jobs", "4"]);
+        assert_eq!(actual.unwrap(), expected);
+        assert!(parse(&["all", "--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn parse_run_with_format() {
+        let expected = Command::Run {
+            year: 2022,
+            day: 3,
+            part: None,
+            input: Input::Default,
+            stream: false,
+            format: Format::Json,
+            visualize: None,
+            export: None,
+        };
+        assert_eq!(parse(&["run", "3", "-f", "json"]).unwrap(), expected);
+        assert!(parse(&["run", "3", "--format", "xml"]).is_err());
+        assert!(parse(&["bench", "3", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn parse_bench() {
+        let expected = Command::Bench {
+            year: 2022,
+            day: 8,
+            part: None,
+            input: Input::Default,
+            config: BenchConfig {
+                warmup: 10,
+                runs: 500,
+            },
+        };
+        assert_eq!(parse(&["bench", "8", "--runs", "500"]).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_verify() {
+        let expected = Command::Verify {
+            answers: "answers.txt".to_owned(),
+        };
+        assert_eq!(parse(&["verify"]).unwrap(), expected);
+        let expected = Command::Verify {
+            answers: "old.txt".to_owned(),
+        };
+        assert_eq!(
+            parse(&["verify", "--answers", "old.txt"]).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn parse_fetch() {
+        let expected = Command::Fetch {
+            year: 2022,
+            day: 6,
+            force: true,
+        };
+        assert_eq!(parse(&["fetch", "--force", "6"]).unwrap(), expected);
+        assert!(parse(&["fetch"]).is_err());
+    }
+
+    #[test]
+    fn parse_submit() {
+        let expected = Command::Submit {
+            year: 2022,
+            day: 4,
+            part: Part::Two,
+        };
+        assert_eq!(parse(&["submit", "4", "2"]).unwrap(), expected);
+        assert_eq!(parse(&["submit", "2022", "4", "2"]).unwrap(), expected);
+        assert!(parse(&["submit", "4"]).is_err());
+        assert!(parse(&["submit", "4", "3"]).is_err());
+    }
+
+    #[test]
+    fn parse_watch() {
+        let expected = Command::Watch { year: 2022, day: 7 };
+        assert_eq!(parse(&["watch", "7"]).unwrap(), expected);
+        assert!(parse(&["watch", "7", "8"]).is_err());
+    }
+
+    #[test]
+    fn parse_samples() {
+        let expected = Command::Samples {
+            year: 2022,
+            day: 9,
+            page: "day9.html".into(),
+            force: false,
+        };
+        assert_eq!(parse(&["samples", "9", "day9.html"]).unwrap(), expected);
+        assert!(parse(&["samples", "day9.html"]).is_err());
+    }
+
+    #[test]
+    fn parse_generate() {
+        let expected = Command::Generate {
+            year: 2022,
+            day: 5,
+            size: 1000,
+            seed: None,
+        };
+        assert_eq!(parse(&["generate", "5"]).unwrap(), expected);
+        let expected = Command::Generate {
+            year: 2022,
+            day: 5,
+            size: 50,
+            seed: Some(42),
+        };
+        let actual = parse(&["generate", "5", "--seed", "42", "--size", "50"]);
+        assert_eq!(actual.unwrap(), expected);
+        assert!(parse(&["generate", "5", "--seed", "-1"]).is_err());
+    }
+
+    #[test]
+    fn parse_fuzz() {
+        let expected = Command::Fuzz {
+            year: 2022,
+            day: 8,
+            runs: 500,
+            seed: None,
+        };
+        assert_eq!(parse(&["fuzz", "8", "--runs", "500"]).unwrap(), expected);
+        assert!(parse(&["fuzz", "--seed", "1"]).is_err());
+    }
+
+    #[test]
+    fn parse_new() {
+        let expected = Command::New {
+            year: 2022,
+            day: 11,
+            title: Some("Monkey in the Middle".to_owned()),
+        };
+        let actual = parse(&["new", "11", "--title", "Monkey in the Middle"]);
+        assert_eq!(actual.unwrap(), expected);
+        let expected = Command::New {
+            year: 2023,
+            day: 1,
+            title: None,
+        };
+        assert_eq!(parse(&["new", "2023", "1"]).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_bad_arguments() {
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "26"]).is_err());
+        assert!(parse(&["run", "1", "--part", "3"]).is_err());
+        assert!(parse(&["run", "1", "--input"]).is_err());
+        assert!(parse(&["run", "1", "--runs", "10"]).is_err());
+        assert!(parse(&["bench", "1", "--runs", "many"]).is_err());
+        assert!(parse(&["bench", "1", "--stream"]).is_err());
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["fly", "1"]).is_err());
+    }
+}
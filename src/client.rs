@@ -0,0 +1,327 @@
+//! Talking to the Advent of Code website, or to a stub server standing in for it
+use crate::runner::Part;
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Holds the session token and other settings. It is not checked in, since the token logs you in.
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+/// Overrides `session` in [`CONFIG_FILE`]
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Overrides `base_url` in [`CONFIG_FILE`]
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The website asks automated tools to say who they are
+const USER_AGENT: &str = concat!(
+    "aoc-rust-2022/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/KPLauritzen/aoc-rust-2022)"
+);
+
+/// Settings for talking to the website
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// The `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Read the config file at `path` if it exists, and apply [`SESSION_VAR`] and [`BASE_URL_VAR`]
+    /// on top of it
+    pub fn load(path: &Path) -> Result<Self> {
+        let config = if path.exists() {
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("Could not read {}", path.display()))?;
+            ClientConfig::parse(&text).with_context(|| format!("Error in {}", path.display()))?
+        } else {
+            ClientConfig::default()
+        };
+        Ok(config.with_overrides(env::var(SESSION_VAR).ok(), env::var(BASE_URL_VAR).ok()))
+    }
+
+    /// Parse `key = value` lines. Blank lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut config = ClientConfig::default();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("Line {}: expected 'key = value'", idx + 1))?;
+            let value = value.trim().to_owned();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                key => bail!("Line {}: unknown setting '{}'", idx + 1, key),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Replace the settings that are given, ignoring empty values
+    pub fn with_overrides(mut self, session: Option<String>, base_url: Option<String>) -> Self {
+        if let Some(session) = session.filter(|session| !session.is_empty()) {
+            self.session = Some(session);
+        }
+        if let Some(base_url) = base_url.filter(|base_url| !base_url.is_empty()) {
+            self.base_url = base_url;
+        }
+        self
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    config: ClientConfig,
+}
+
+impl Client {
+    /// A client for `config`. A missing session token is only an error once a request needs it.
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client { agent, config }
+    }
+
+    /// The URL of the puzzle for `day` of `year`, which the other pages are under
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.config.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+
+    /// Download the puzzle input for `day` of `year`
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.puzzle_url(year, day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie()?)
+            .call()
+            .map_err(|err| request_error(err, day, &url))?;
+        response
+            .into_string()
+            .with_context(|| format!("Could not read the response from {}", url))
+    }
+
+    /// Submit `answer` for `part` of `day` of `year`, returning the page the website replies with
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.puzzle_url(year, day));
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|err| request_error(err, day, &url))?;
+        response
+            .into_string()
+            .with_context(|| format!("Could not read the response from {}", url))
+    }
+
+    fn cookie(&self) -> Result<String> {
+        match &self.config.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => bail!(
+                "No session token. Set {} or add 'session = ...' to {}, using the session cookie from a logged in browser",
+                SESSION_VAR,
+                CONFIG_FILE
+            ),
+        }
+    }
+}
+
+/// Explain the status codes the website uses for common mistakes
+fn request_error(err: ureq::Error, day: u8, url: &str) -> anyhow::Error {
+    match err {
+        ureq::Error::Status(404, _) => {
+            anyhow::anyhow!("Day {} is not unlocked yet (404 from {})", day, url)
+        }
+        ureq::Error::Status(400, _) => anyhow::anyhow!(
+            "The session token was rejected (400 from {}). Log in again and update it",
+            url
+        ),
+        ureq::Error::Status(code, _) => anyhow::anyhow!("Got status {} from {}", code, url),
+        ureq::Error::Transport(err) => anyhow::anyhow!("Could not reach {}: {}", url, err),
+    }
+}
+
+/// What [`fetch_input`] did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The file was already there, so nothing was downloaded
+    AlreadyExists,
+}
+
+/// Download the input for `day` of `year` to `path`, unless it is already there and `force` is not
+/// set
+pub fn fetch_input(
+    client: &Client,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+) -> Result<Fetched> {
+    if path.exists() && !force {
+        return Ok(Fetched::AlreadyExists);
+    }
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input).with_context(|| format!("Could not write {}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serve one canned `(status, body)` response per connection on a free local port. Returns
+    /// the base URL, and a handle that gives every request that was received.
+    pub(crate) fn stub_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut body_len = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        body_len = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                }
+                let mut request_body = vec![0; body_len];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8(request_body).unwrap());
+                requests.push(request);
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn client(base_url: &str) -> Client {
+        Client::new(ClientConfig {
+            session: Some("abc123".to_owned()),
+            base_url: base_url.to_owned(),
+        })
+    }
+
+    #[test]
+    fn parse_config() {
+        let text = "# comment\nsession = abc123\n\nbase_url=http://localhost:8000/\n";
+        let config = ClientConfig::parse(text).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8000/");
+        assert!(ClientConfig::parse("session abc123").is_err());
+        assert!(ClientConfig::parse("year = 2021").is_err());
+    }
+
+    #[test]
+    fn overrides_replace_the_config_file() {
+        let config = ClientConfig::parse("session = old").unwrap();
+        let config = config.with_overrides(Some("new".to_owned()), Some(String::new()));
+        assert_eq!(config.session.as_deref(), Some("new"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn download_input() {
+        let (base_url, server) = stub_server(vec![(200, "1000\n2000\n")]);
+        let input = client(&base_url).input(2022, 1).unwrap();
+        assert_eq!(input, "1000\n2000\n");
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn explain_error_responses() {
+        let (base_url, server) = stub_server(vec![(404, "Not found"), (400, "Please log in")]);
+        let err = client(&base_url).input(2022, 25).unwrap_err();
+        assert!(err.to_string().contains("not unlocked yet"), "{}", err);
+        let err = client(&base_url).input(2021, 1).unwrap_err();
+        assert!(
+            err.to_string().contains("session token was rejected"),
+            "{}",
+            err
+        );
+        server.join().unwrap();
+
+        let no_session = Client::new(ClientConfig::default());
+        assert!(no_session.input(2022, 1).is_err());
+    }
+
+    #[test]
+    fn fetch_does_not_overwrite() {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("input/day03.txt");
+        let (base_url, server) = stub_server(vec![(200, "new\n"), (200, "forced\n")]);
+        let client = client(&base_url);
+
+        assert_eq!(
+            fetch_input(&client, 2022, 3, &path, false).unwrap(),
+            Fetched::Downloaded
+        );
+        fs::write(&path, "edited\n").unwrap();
+        assert_eq!(
+            fetch_input(&client, 2022, 3, &path, false).unwrap(),
+            Fetched::AlreadyExists
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "edited\n");
+        assert_eq!(
+            fetch_input(&client, 2022, 3, &path, true).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "forced\n");
+        assert_eq!(server.join().unwrap().len(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+// https://adventofcode.com/2022/day/1
+
+use crate::runner::Part;
+use crate::{groups, Answer, AocError, LineStream, Solution, Solver};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        let elves = get_total_calories(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || Ok(max_calories(&elves)?.into())),
+            Part::Two => Box::new(move || Ok(top_three_calories(&elves).into())),
+        })
+    }
+
+    fn stream(&self, part: Part, lines: &mut LineStream) -> Option<anyhow::Result<Answer>> {
+        Some(stream_part(part, lines))
+    }
+}
+
+fn stream_part(part: Part, lines: &mut LineStream) -> anyhow::Result<Answer> {
+    let top_three = stream_top_three(lines)?;
+    Ok(match part {
+        Part::One => max_calories(&top_three)?.into(),
+        Part::Two => top_three_calories(&top_three).into(),
+    })
+}
+
+fn parse_calories(idx: usize, line: &str) -> Result<i32, AocError> {
+    line.parse::<i32>()
+        .map_err(|err| AocError::new(idx, line, format!("bad calories: {}", err)))
+}
+
+/// Add the calories on `line` to the total of an elf
+fn add_calories(total: i32, idx: usize, line: &str) -> Result<i32, AocError> {
+    total
+        .checked_add(parse_calories(idx, line)?)
+        .ok_or_else(|| AocError::new(idx, line, "the elf carries too many calories to count"))
+}
+
+fn get_total_calories(input: &[String]) -> Result<Vec<i32>, AocError> {
+    groups(input)
+        .map(|elf| {
+            elf.enumerate()
+                .try_fold(0, |total, (idx, line)| add_calories(total, idx, line))
+        })
+        .collect()
+}
+
+/// The three highest totals, in descending order, without keeping every elf in memory
+fn stream_top_three(lines: &mut LineStream) -> anyhow::Result<Vec<i32>> {
+    let mut top_three = Vec::new();
+    // Blank lines are handled like `groups` does, so extra ones don't make empty elves
+    let mut cal_count = None;
+    for (idx, line) in lines.enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            if let Some(calories) = cal_count.take() {
+                keep_top_three(&mut top_three, calories);
+            }
+        } else {
+            cal_count = Some(add_calories(cal_count.unwrap_or(0), idx, &line)?);
+        }
+    }
+    if let Some(calories) = cal_count {
+        keep_top_three(&mut top_three, calories);
+    }
+    Ok(top_three)
+}
+
+fn keep_top_three(top_three: &mut Vec<i32>, calories: i32) {
+    top_three.push(calories);
+    top_three.sort_by(|a, b| b.cmp(a));
+    top_three.truncate(3);
+}
+
+fn part_1(input: &[String]) -> Result<i32, AocError> {
+    let elves = get_total_calories(input)?;
+
+    max_calories(&elves)
+}
+
+fn max_calories(elves: &[i32]) -> Result<i32, AocError> {
+    elves
+        .iter()
+        .max()
+        .copied()
+        .ok_or_else(|| AocError::input("there are no elves in the input"))
+}
+
+fn part_2(input: &[String]) -> Result<i64, AocError> {
+    let elves = get_total_calories(input)?;
+
+    Ok(top_three_calories(&elves))
+}
+
+/// Three totals always fit in an `i64`
+fn top_three_calories(elves: &[i32]) -> i64 {
+    let mut elves = elves.to_vec();
+    elves.sort();
+    elves.reverse();
+    elves
+        .iter()
+        .take(3)
+        .map(|&calories| i64::from(calories))
+        .sum()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lines;
+
+    #[test]
+    fn bad_calories_are_an_error() {
+        let input = vec!["1000".to_owned(), "".to_owned(), "lots".to_owned()];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.text, "lots");
+    }
+
+    #[test]
+    fn too_many_calories_are_an_error() {
+        let input = vec!["2147483647".to_owned(), "89".to_owned()];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (Some(2), "89"));
+        let mut stream = input.into_iter().map(Ok);
+        assert!(stream_top_three(&mut stream).is_err());
+
+        let input: Vec<String> = ["2147483647", "", "2147483647", "", "2147483647"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(part_2(&input).unwrap(), 3 * 2147483647);
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let mut sample = lines("input/day01_sample.txt").unwrap();
+        let result = Day01.stream(Part::One, &mut sample).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(24000));
+        let mut sample = lines("input/day01_sample.txt").unwrap();
+        let result = Day01.stream(Part::Two, &mut sample).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(45000));
+    }
+
+    #[test]
+    fn extra_blank_lines_and_empty_elves() {
+        let input: Vec<String> = ["", "0", "", "", "3", "4", ""]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(get_total_calories(&input).unwrap(), vec![0, 7]);
+        let mut stream = input.into_iter().map(Ok);
+        assert_eq!(stream_top_three(&mut stream).unwrap(), vec![7, 0]);
+    }
+}
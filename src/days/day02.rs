@@ -0,0 +1,275 @@
+// https://adventofcode.com/2022/day/2
+
+use crate::runner::Part;
+use crate::{Answer, AocError, LineStream, Solution, Solver};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Throw {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Outcome {
+    Win = 6,
+    Loss = 0,
+    Draw = 3,
+}
+
+impl Throw {
+    fn get_outcome_against(&self, other: Throw) -> Outcome {
+        match self {
+            Throw::Rock => match other {
+                Throw::Rock => Outcome::Draw,
+                Throw::Paper => Outcome::Loss,
+                Throw::Scissors => Outcome::Win,
+            },
+            Throw::Paper => match other {
+                Throw::Rock => Outcome::Win,
+                Throw::Paper => Outcome::Draw,
+                Throw::Scissors => Outcome::Loss,
+            },
+            Throw::Scissors => match other {
+                Throw::Rock => Outcome::Loss,
+                Throw::Paper => Outcome::Win,
+                Throw::Scissors => Outcome::Draw,
+            },
+        }
+    }
+
+    fn get_needed_move(&self, outcome: Outcome) -> Throw {
+        match (self, outcome) {
+            (Throw::Rock, Outcome::Win) => Throw::Paper,
+            (Throw::Rock, Outcome::Loss) => Throw::Scissors,
+            (Throw::Rock, Outcome::Draw) => Throw::Rock,
+
+            (Throw::Paper, Outcome::Win) => Throw::Scissors,
+            (Throw::Paper, Outcome::Loss) => Throw::Rock,
+            (Throw::Paper, Outcome::Draw) => Throw::Paper,
+
+            (Throw::Scissors, Outcome::Win) => Throw::Rock,
+            (Throw::Scissors, Outcome::Loss) => Throw::Paper,
+            (Throw::Scissors, Outcome::Draw) => Throw::Scissors,
+        }
+    }
+}
+
+fn match_score(rps: Throw, outcome: Outcome) -> i32 {
+    (rps as i32) + (outcome as i32)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        Ok(match part {
+            Part::One => {
+                let (opp_moves, my_moves) = parse_moves(input)?;
+                Box::new(move || Ok(score_moves(&opp_moves, &my_moves).into()))
+            }
+            Part::Two => {
+                let (opp_moves, desired_outcomes) = parse_moves_and_outcomes(input)?;
+                Box::new(move || Ok(score_outcomes(&opp_moves, &desired_outcomes).into()))
+            }
+        })
+    }
+
+    fn stream(&self, part: Part, lines: &mut LineStream) -> Option<anyhow::Result<Answer>> {
+        Some(stream_score(part, lines).map(Answer::from))
+    }
+}
+
+/// The total score, adding up one line at a time. In an `i64`, since a streamed input can have
+/// more rounds than an `i32` can count the score of.
+fn stream_score(part: Part, lines: &mut LineStream) -> anyhow::Result<i64> {
+    let mut running_score: i64 = 0;
+    for (idx, line) in lines.enumerate() {
+        let (opp_move, second) = parse_line(idx, &line?)?;
+        running_score += i64::from(match part {
+            Part::One => score_move(opp_move, MY_MOVES[second]),
+            Part::Two => score_outcome(opp_move, OUTCOMES[second]),
+        });
+    }
+    Ok(running_score)
+}
+
+/// What X, Y and Z mean in part 1
+const MY_MOVES: [Throw; 3] = [Throw::Rock, Throw::Paper, Throw::Scissors];
+/// What X, Y and Z mean in part 2
+const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+/// Split a line like "A Y" into its two letters
+fn split_line(idx: usize, line: &str) -> Result<(&str, &str), AocError> {
+    line.split_once(' ')
+        .ok_or_else(|| AocError::new(idx, line, "expected two letters separated by a space"))
+}
+
+fn parse_opp_move(idx: usize, line: &str, opp_move: &str) -> Result<Throw, AocError> {
+    match opp_move {
+        "A" => Ok(Throw::Rock),
+        "B" => Ok(Throw::Paper),
+        "C" => Ok(Throw::Scissors),
+
+        _ => Err(AocError::new(idx, line, "expected A, B or C").at_column(0)),
+    }
+}
+
+/// Parse a line like "A Y" into the opponent's move, and 0, 1 or 2 for X, Y or Z
+fn parse_line(idx: usize, line: &str) -> Result<(Throw, usize), AocError> {
+    let (opp_move, second) = split_line(idx, line)?;
+    let column = opp_move.chars().count() + 1;
+    let opp_move = parse_opp_move(idx, line, opp_move)?;
+    let second = ["X", "Y", "Z"]
+        .iter()
+        .position(|&letter| letter == second)
+        .ok_or_else(|| AocError::new(idx, line, "expected X, Y or Z").at_column(column))?;
+    Ok((opp_move, second))
+}
+
+fn parse_moves(input: &[String]) -> Result<(Vec<Throw>, Vec<Throw>), AocError> {
+    let mut opp_moves: Vec<Throw> = Vec::new();
+    let mut my_moves: Vec<Throw> = Vec::new();
+    for (idx, line) in input.iter().enumerate() {
+        let (opp_move, my_move) = parse_line(idx, line)?;
+        opp_moves.push(opp_move);
+        my_moves.push(MY_MOVES[my_move]);
+    }
+    Ok((opp_moves, my_moves))
+}
+
+fn part_1(input: &[String]) -> Result<i32, AocError> {
+    let (opp_moves, my_moves) = parse_moves(input)?;
+    Ok(score_moves(&opp_moves, &my_moves))
+}
+
+fn score_moves(opp_moves: &[Throw], my_moves: &[Throw]) -> i32 {
+    let mut running_score = 0;
+    for (&opp_move, &my_move) in opp_moves.iter().zip(my_moves) {
+        running_score += score_move(opp_move, my_move);
+    }
+    running_score
+}
+
+fn score_move(opp_move: Throw, my_move: Throw) -> i32 {
+    let outcome = my_move.get_outcome_against(opp_move);
+    match_score(my_move, outcome)
+}
+
+fn parse_moves_and_outcomes(input: &[String]) -> Result<(Vec<Throw>, Vec<Outcome>), AocError> {
+    let mut opp_moves: Vec<Throw> = Vec::new();
+    let mut outcomes: Vec<Outcome> = Vec::new();
+    for (idx, line) in input.iter().enumerate() {
+        let (opp_move, outcome) = parse_line(idx, line)?;
+        opp_moves.push(opp_move);
+        outcomes.push(OUTCOMES[outcome]);
+    }
+    Ok((opp_moves, outcomes))
+}
+
+fn part_2(input: &[String]) -> Result<i32, AocError> {
+    let (opp_moves, desired_outcomes) = parse_moves_and_outcomes(input)?;
+    Ok(score_outcomes(&opp_moves, &desired_outcomes))
+}
+
+fn score_outcomes(opp_moves: &[Throw], desired_outcomes: &[Outcome]) -> i32 {
+    let mut running_score = 0;
+    for (&opp_move, &desired_outcome) in opp_moves.iter().zip(desired_outcomes) {
+        running_score += score_outcome(opp_move, desired_outcome);
+    }
+    running_score
+}
+
+fn score_outcome(opp_move: Throw, desired_outcome: Outcome) -> i32 {
+    // println!("Input: {:?}, {:?} ", opp_move, desired_outcome);
+
+    let my_move = opp_move.get_needed_move(desired_outcome);
+    // println!("My move: {:?}", my_move);
+
+    match_score(my_move, desired_outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{file_to_vec, lines};
+
+    #[test]
+    fn test_match_score_1() {
+        let rps = Throw::Paper;
+        let outcome = Outcome::Win;
+        let actual_score = match_score(rps, outcome);
+        assert_eq!(actual_score, 8)
+    }
+
+    #[test]
+    fn test_match_score_2() {
+        let rps = Throw::Rock;
+        let outcome = Outcome::Loss;
+        let actual_score = match_score(rps, outcome);
+        assert_eq!(actual_score, 1)
+    }
+
+    #[test]
+    fn test_match_score_3() {
+        let rps = Throw::Scissors;
+        let outcome = Outcome::Draw;
+        let actual_score = match_score(rps, outcome);
+        assert_eq!(actual_score, 6)
+    }
+
+    #[test]
+    fn test_rps_ordering() {
+        let expected_outcome = Outcome::Loss;
+        let actual_outcome = Throw::Rock.get_outcome_against(Throw::Paper);
+        assert_eq!(expected_outcome, actual_outcome);
+
+        let expected_outcome = Outcome::Win;
+        let actual_outcome = Throw::Paper.get_outcome_against(Throw::Rock);
+        assert_eq!(expected_outcome, actual_outcome);
+
+        let expected_outcome = Outcome::Draw;
+        let actual_outcome = Throw::Scissors.get_outcome_against(Throw::Scissors);
+        assert_eq!(expected_outcome, actual_outcome);
+    }
+
+    #[test]
+    fn test_parsing_moves() {
+        let filename = "input/day02_sample.txt";
+        let input = file_to_vec(filename).unwrap();
+        let (opp_moves, _my_moves) = parse_moves(&input).unwrap();
+        assert_eq!(opp_moves, vec![Throw::Rock, Throw::Paper, Throw::Scissors]);
+    }
+
+    #[test]
+    fn unknown_letter_is_an_error() {
+        let input = vec!["A Y".to_owned(), "B Q".to_owned()];
+        let err = parse_moves(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(3)));
+
+        let input = vec!["AY".to_owned()];
+        assert!(parse_moves_and_outcomes(&input).is_err());
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let mut sample = lines("input/day02_sample.txt").unwrap();
+        let result = Day02.stream(Part::Two, &mut sample).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(12));
+    }
+}
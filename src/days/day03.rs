@@ -0,0 +1,164 @@
+// https://adventofcode.com/2022/day/3
+
+use crate::runner::Part;
+use crate::{Answer, AocError, LineStream, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn stream(&self, part: Part, lines: &mut LineStream) -> Option<anyhow::Result<Answer>> {
+        Some(stream_score(part, lines).map(Answer::from))
+    }
+}
+
+/// The sum of priorities, keeping at most one group of elves in memory. In an `i64`, since a
+/// streamed input can have more rucksacks than an `i32` can add up.
+fn stream_score(part: Part, lines: &mut LineStream) -> anyhow::Result<i64> {
+    let mut score: i64 = 0;
+    let mut group = Vec::new();
+    let mut n_lines = 0;
+    for (idx, line) in lines.enumerate() {
+        let line = line?;
+        n_lines += 1;
+        match part {
+            Part::One => score += i64::from(rucksack_priority(idx, &line)?),
+            Part::Two => {
+                group.push(line);
+                if group.len() == 3 {
+                    score += i64::from(group_priority(idx - 2, &group)?);
+                    group.clear();
+                }
+            }
+        }
+    }
+    if !group.is_empty() {
+        // This is an error, since the group is too small
+        score += i64::from(group_priority(n_lines - group.len(), &group)?);
+    }
+    Ok(score)
+}
+
+fn part_1(input: &[String]) -> Result<i32, AocError> {
+    let mut score = 0;
+    for (idx, line) in input.iter().enumerate() {
+        score += rucksack_priority(idx, line)?;
+    }
+    Ok(score)
+}
+
+fn rucksack_priority(idx: usize, line: &str) -> Result<i32, AocError> {
+    check_items(idx, line)?;
+    if !line.len().is_multiple_of(2) {
+        return Err(AocError::new(
+            idx,
+            line,
+            "the compartments are not the same size",
+        ));
+    }
+    let (left, right) = split_line_at_middle(line);
+    let overlap = find_overlapping_type(left, right)
+        .ok_or_else(|| AocError::new(idx, line, "the compartments have no item in common"))?;
+    Ok(get_priority(overlap))
+}
+
+/// Check that every item in the rucksack is a letter, which also makes it safe to split by bytes
+fn check_items(idx: usize, line: &str) -> Result<(), AocError> {
+    match line.chars().position(|c| !c.is_ascii_alphabetic()) {
+        Some(column) => Err(AocError::new(idx, line, "items must be letters").at_column(column)),
+        None => Ok(()),
+    }
+}
+
+fn split_line_at_middle(line: &str) -> (&str, &str) {
+    let split_idx = line.len() / 2;
+    let (left, right) = line.split_at(split_idx);
+    (left, right)
+}
+
+fn find_overlapping_type(left: &str, right: &str) -> Option<char> {
+    left.chars().find(|element| right.contains(*element))
+}
+
+fn get_priority(overlap: char) -> i32 {
+    let alphabet = " abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    // Items are checked to be letters, so they are always found
+    let priority = alphabet.find(overlap).unwrap_or(0);
+    priority as i32
+}
+
+fn part_2(input: &[String]) -> Result<i32, AocError> {
+    let mut score = 0;
+    for (group_idx, group) in input.chunks(3).enumerate() {
+        score += group_priority(group_idx * 3, group)?;
+    }
+    Ok(score)
+}
+
+/// The priority of the badge of a group of three elves, starting at the line at `idx`
+fn group_priority(idx: usize, group: &[String]) -> Result<i32, AocError> {
+    for (offset, line) in group.iter().enumerate() {
+        check_items(idx + offset, line)?;
+    }
+    let [line1, line2, line3] = group else {
+        return Err(AocError::new(
+            idx,
+            &group[0],
+            "the last group has less than three elves",
+        ));
+    };
+    let overlap_1_2: String = line1.chars().filter(|x| line2.contains(*x)).collect();
+    let overlap_1_2_3 = find_overlapping_type(&overlap_1_2, line3)
+        .ok_or_else(|| AocError::new(idx, line1, "the group has no item in common"))?;
+    Ok(get_priority(overlap_1_2_3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lines_from_reader, ReadOptions};
+
+    #[test]
+    fn bad_rucksacks_are_an_error() {
+        let input = vec!["ab-a".to_owned()];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!(err.column, Some(3));
+
+        let input = vec!["abcd".to_owned()];
+        assert!(part_1(&input).is_err());
+
+        let input = vec!["aB".to_owned(), "aC".to_owned()];
+        let err = part_2(&input).unwrap_err();
+        assert_eq!(err.line, Some(1));
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let sample = std::fs::read_to_string("input/day03_sample.txt").unwrap();
+        let mut stream = lines_from_reader(sample.as_bytes(), ReadOptions::default());
+        let result = Day03.stream(Part::Two, &mut stream).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(70));
+
+        let (first_five, _) = sample.trim_end().rsplit_once('\n').unwrap();
+        let mut stream = lines_from_reader(first_five.as_bytes(), ReadOptions::default());
+        let err = Day03.stream(Part::Two, &mut stream).unwrap().unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("4: the last group has less than three elves"));
+    }
+}
@@ -0,0 +1,142 @@
+// https://adventofcode.com/2022/day/4
+
+use crate::interval::Interval;
+use crate::parse::{int, literal, parse_line, Cursor, ParseError};
+use crate::runner::Part;
+use crate::{Answer, AocError, LineStream, Solution, Solver};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        let pairs = parse_pairs(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || Ok(count_fully_contained(&pairs).into())),
+            Part::Two => Box::new(move || Ok(count_any_overlap(&pairs).into())),
+        })
+    }
+
+    fn stream(&self, part: Part, lines: &mut LineStream) -> Option<anyhow::Result<Answer>> {
+        Some(stream_count(part, lines).map(Answer::from))
+    }
+}
+
+/// Count the pairs one line at a time
+fn stream_count(part: Part, lines: &mut LineStream) -> anyhow::Result<usize> {
+    let mut count = 0;
+    for (idx, line) in lines.enumerate() {
+        let pair = parse_pair(idx, &line?)?;
+        let counts = match part {
+            Part::One => is_fully_contained(&pair),
+            Part::Two => has_any_overlap(&pair),
+        };
+        if counts {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+type Pair = (Interval<i32>, Interval<i32>);
+
+fn parse_pairs(input: &[String]) -> Result<Vec<Pair>, AocError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| parse_pair(idx, line))
+        .collect()
+}
+
+fn parse_pair(idx: usize, line: &str) -> Result<Pair, AocError> {
+    let pair = parse_line(line, |c: &mut Cursor| {
+        let first = c.parse(range)?;
+        c.parse(literal(","))?;
+        Ok((first, c.parse(range)?))
+    })
+    .map_err(|err| err.in_line(idx, line))?;
+    match pair {
+        (Some(first), Some(second)) => Ok((first, second)),
+        _ => Err(AocError::new(idx, line, "a range ends before it starts")),
+    }
+}
+
+/// A range like `2-4`
+fn range(c: &mut Cursor) -> Result<Option<Interval<i32>>, ParseError> {
+    let start = c.parse(int())?;
+    c.parse(literal("-"))?;
+    Ok(Interval::new(start, c.parse(int())?))
+}
+
+// How many pairs, where one fully contains the other
+fn part_1(input: &[String]) -> Result<usize, AocError> {
+    Ok(count_fully_contained(&parse_pairs(input)?))
+}
+
+fn count_fully_contained(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|pair| is_fully_contained(pair)).count()
+}
+
+fn is_fully_contained((first, second): &Pair) -> bool {
+    first.contains_interval(second) || second.contains_interval(first)
+}
+
+// How many pairs where there is any overlap
+fn part_2(input: &[String]) -> Result<usize, AocError> {
+    Ok(count_any_overlap(&parse_pairs(input)?))
+}
+
+fn count_any_overlap(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|pair| has_any_overlap(pair)).count()
+}
+
+fn has_any_overlap((first, second): &Pair) -> bool {
+    first.overlaps(second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lines;
+
+    #[test]
+    fn test_has_any_overlap() {
+        let actual = has_any_overlap(&parse_pair(0, "1-2,3-4").unwrap());
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = has_any_overlap(&parse_pair(0, "1-3,3-4").unwrap());
+        let expected = true;
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn bad_pair_is_an_error() {
+        let input = vec!["2-4,6-8".to_owned(), "2-4;6-8".to_owned()];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert!(parse_pair(0, "4-2,6-8").is_err());
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let mut sample = lines("input/day04_sample.txt").unwrap();
+        let result = Day04.stream(Part::Two, &mut sample).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(4));
+    }
+}
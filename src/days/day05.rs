@@ -0,0 +1,396 @@
+// https://adventofcode.com/2022/day/5
+use crate::parse::{int, literal, parse_line, Cursor};
+use crate::runner::Part;
+use crate::visualize::{Frame, Style, Visualize};
+use crate::{groups, Answer, AocError, Group, Solution, Solver};
+
+const N_CHARS_PER_STACK: usize = 4;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        let (stacks, moves) = parse_input(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || {
+                let stacks = move_supply_crates(&moves, stacks.clone())?;
+                Ok(get_top_of_stacks(stacks)?.into())
+            }),
+            Part::Two => Box::new(move || {
+                let stacks = move_supply_crates_9001(&moves, stacks.clone())?;
+                Ok(get_top_of_stacks(stacks)?.into())
+            }),
+        })
+    }
+
+    fn visualize(
+        &self,
+        part: Part,
+        input: &[String],
+    ) -> Option<anyhow::Result<Box<dyn Visualize>>> {
+        let animation = CrateAnimation::new(part, input);
+        Some(
+            animation
+                .map(|animation| Box::new(animation) as _)
+                .map_err(Into::into),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CrateMove {
+    n_moves: usize,
+    /// Stacks are numbered from 1, like in the input
+    from_stack: usize,
+    to_stack: usize,
+    /// Where the move is in the input, for error messages
+    line_idx: usize,
+    line: String,
+}
+
+fn part_1(input: &[String]) -> Result<String, AocError> {
+    let (mut stacks, moves) = parse_input(input)?;
+    stacks = move_supply_crates(&moves, stacks)?;
+    get_top_of_stacks(stacks)
+}
+
+fn parse_input(input: &[String]) -> Result<(Vec<Vec<char>>, Vec<CrateMove>), AocError> {
+    let mut sections = groups(input);
+    let drawing = sections
+        .next()
+        .ok_or_else(|| AocError::input("the input is empty"))?;
+    let moves = sections.next();
+    if let Some(extra) = sections.next() {
+        let message = "expected a single blank line between the stacks and the moves";
+        return Err(AocError::new(extra.start, &extra.lines[0], message));
+    }
+
+    // The drawing ends with the stack numbers. Count them, since editors may strip the
+    // trailing spaces of the crate lines.
+    let (numbers_line, crate_lines) = match drawing.lines.split_last() {
+        Some((numbers_line, crate_lines)) if !numbers_line.contains('[') => {
+            (numbers_line, crate_lines)
+        }
+        _ => return Err(AocError::input("missing the line with the stack numbers")),
+    };
+    let n_stacks = numbers_line.split_whitespace().count();
+    let crate_lines = Group {
+        start: drawing.start,
+        lines: crate_lines,
+    };
+    let stacks: Vec<Vec<char>> = get_stacks(crate_lines, n_stacks)?;
+    let moves = match moves {
+        Some(moves) => get_moves(moves, n_stacks)?,
+        None => Vec::new(),
+    };
+    Ok((stacks, moves))
+}
+
+fn get_stacks(crate_lines: Group, n_stacks: usize) -> Result<Vec<Vec<char>>, AocError> {
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    // create empty stacks
+    for _ in 0..n_stacks {
+        stacks.push(Vec::new());
+    }
+    for (idx, line) in crate_lines.enumerate() {
+        // Go through every 4th char in the line, skipping the first char.
+        for (stack_idx, supply_crate) in line.chars().skip(1).step_by(N_CHARS_PER_STACK).enumerate()
+        {
+            if supply_crate == ' ' {
+                continue;
+            }
+            let column_idx = 1 + stack_idx * N_CHARS_PER_STACK;
+            let stack = stacks.get_mut(stack_idx).ok_or_else(|| {
+                let message = format!("there are only {} stacks", n_stacks);
+                AocError::new(idx, line, message).at_column(column_idx)
+            })?;
+            stack.push(supply_crate);
+        }
+    }
+    for stack in stacks.iter_mut() {
+        stack.reverse()
+    }
+    Ok(stacks)
+}
+
+fn get_moves(move_lines: Group, n_stacks: usize) -> Result<Vec<CrateMove>, AocError> {
+    let mut moves = Vec::new();
+    for (line_idx, line) in move_lines.enumerate() {
+        // Parse each line of commands according to the given pattern
+        let (n_moves, from_stack, to_stack) = parse_line(line, |c: &mut Cursor| {
+            c.parse(literal("move "))?;
+            let n_moves = c.parse(int())?;
+            c.parse(literal(" from "))?;
+            let from_stack = c.parse(int())?;
+            c.parse(literal(" to "))?;
+            Ok((n_moves, from_stack, c.parse(int())?))
+        })
+        .map_err(|err| err.in_line(line_idx, line))?;
+        for stack in [from_stack, to_stack] {
+            if !(1..=n_stacks).contains(&stack) {
+                let message = format!("there is no stack {}", stack);
+                return Err(AocError::new(line_idx, line, message));
+            }
+        }
+        moves.push(CrateMove {
+            n_moves,
+            from_stack,
+            to_stack,
+            line_idx,
+            line: line.clone(),
+        });
+    }
+    Ok(moves)
+}
+
+/// Take the top crate of the stack `crate_move` moves from
+fn pop_crate(stacks: &mut [Vec<char>], crate_move: &CrateMove) -> Result<char, AocError> {
+    // indexing is off-by-one
+    stacks[crate_move.from_stack - 1].pop().ok_or_else(|| {
+        let message = format!("stack {} has run out of crates", crate_move.from_stack);
+        AocError::new(crate_move.line_idx, &crate_move.line, message)
+    })
+}
+
+fn move_supply_crates(
+    moves: &[CrateMove],
+    mut stacks: Vec<Vec<char>>,
+) -> Result<Vec<Vec<char>>, AocError> {
+    for crate_move in moves {
+        for _ in 0..crate_move.n_moves {
+            let supply_crate = pop_crate(&mut stacks, crate_move)?;
+            stacks[crate_move.to_stack - 1].push(supply_crate);
+        }
+    }
+    Ok(stacks)
+}
+
+fn get_top_of_stacks(stacks: Vec<Vec<char>>) -> Result<String, AocError> {
+    let mut out = String::new();
+    for (idx, stack) in stacks.iter().enumerate() {
+        let top = stack.last().ok_or_else(|| {
+            AocError::input(format!("stack {} is empty after all the moves", idx + 1))
+        })?;
+        out.push(*top)
+    }
+    Ok(out)
+}
+
+fn part_2(input: &[String]) -> Result<String, AocError> {
+    let (mut stacks, moves) = parse_input(input)?;
+    stacks = move_supply_crates_9001(&moves, stacks)?;
+    get_top_of_stacks(stacks)
+}
+
+fn move_supply_crates_9001(
+    moves: &[CrateMove],
+    mut stacks: Vec<Vec<char>>,
+) -> Result<Vec<Vec<char>>, AocError> {
+    for crate_move in moves {
+        // We will simulate the CrateMover9001 by moving crates to a temporary stack one at a time, reversing the order of the temp stack
+        // adding it to the target stack.
+        let mut temp_stack = Vec::new();
+        for _ in 0..crate_move.n_moves {
+            let supply_crate = pop_crate(&mut stacks, crate_move)?;
+            temp_stack.push(supply_crate);
+        }
+        temp_stack.reverse();
+        stacks[crate_move.to_stack - 1].append(&mut temp_stack);
+    }
+    Ok(stacks)
+}
+
+/// The crates being moved one instruction at a time
+struct CrateAnimation {
+    part: Part,
+    stacks: Vec<Vec<char>>,
+    moves: Vec<CrateMove>,
+    n_done: usize,
+    /// The height of the highest stack at any point, so every frame is the same size
+    height: usize,
+}
+
+impl CrateAnimation {
+    fn new(part: Part, input: &[String]) -> Result<Self, AocError> {
+        let (stacks, moves) = parse_input(input)?;
+        // Both cranes leave stacks of the same heights
+        let mut height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut heights = stacks.clone();
+        for crate_move in moves.chunks(1) {
+            let Ok(moved) = move_supply_crates(crate_move, heights) else {
+                break;
+            };
+            height = height.max(moved.iter().map(Vec::len).max().unwrap_or(0));
+            heights = moved;
+        }
+        Ok(CrateAnimation {
+            part,
+            stacks,
+            moves,
+            n_done: 0,
+            height,
+        })
+    }
+}
+
+impl Visualize for CrateAnimation {
+    fn frame(&self) -> Frame {
+        let n_stacks = self.stacks.len();
+        let mut frame = Frame::new(
+            (n_stacks * N_CHARS_PER_STACK).saturating_sub(1),
+            self.height + 1,
+        );
+        let last_move = self.n_done.checked_sub(1).map(|idx| &self.moves[idx]);
+        for (stack_idx, stack) in self.stacks.iter().enumerate() {
+            let column = stack_idx * N_CHARS_PER_STACK;
+            for (level, &supply_crate) in stack.iter().enumerate() {
+                let style = match last_move {
+                    Some(m) if m.to_stack == stack_idx + 1 && level + m.n_moves >= stack.len() => {
+                        Style::Highlight
+                    }
+                    _ if level + 1 == stack.len() => Style::Bright,
+                    _ => Style::Normal,
+                };
+                let text = format!("[{}]", supply_crate);
+                frame.write((self.height - 1 - level, column), &text, style);
+            }
+            let number = (stack_idx + 1).to_string();
+            frame.write((self.height, column + 1), &number, Style::Dim);
+        }
+        let tops: String = self
+            .stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect();
+        frame.caption = match last_move {
+            Some(m) => {
+                let column = (m.to_stack - 1) * N_CHARS_PER_STACK;
+                frame.focus = Some((self.height - self.stacks[m.to_stack - 1].len(), column));
+                format!(
+                    "move {} from {} to {} ({} of {}), top crates: {}",
+                    m.n_moves,
+                    m.from_stack,
+                    m.to_stack,
+                    self.n_done,
+                    self.moves.len(),
+                    tops
+                )
+            }
+            None => format!("{} moves to go, top crates: {}", self.moves.len(), tops),
+        };
+        frame
+    }
+
+    fn step(&mut self) -> anyhow::Result<bool> {
+        let Some(crate_move) = self.moves.get(self.n_done..=self.n_done) else {
+            return Ok(false);
+        };
+        let stacks = std::mem::take(&mut self.stacks);
+        self.stacks = match self.part {
+            Part::One => move_supply_crates(crate_move, stacks)?,
+            Part::Two => move_supply_crates_9001(crate_move, stacks)?,
+        };
+        self.n_done += 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{file_to_vec, file_to_vec_with, ReadOptions};
+
+    #[test]
+    fn parse_stacks() {
+        let filename = "input/day05_sample.txt";
+        let input = file_to_vec(filename).unwrap();
+        let crate_lines = Group {
+            start: 0,
+            lines: &input[..3],
+        };
+        let actual = get_stacks(crate_lines, 3).unwrap();
+        let expected = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bad_moves_are_an_error() {
+        let filename = "input/day05_sample.txt";
+        let mut input = file_to_vec(filename).unwrap();
+        input.push("move 1 from 4 to 1".to_owned());
+        let err = part_1(&input).unwrap_err();
+        assert_eq!(err.line, Some(input.len()));
+
+        input.pop();
+        input.push("move 5 from 3 to 1".to_owned());
+        let err = part_2(&input).unwrap_err();
+        assert_eq!(err.message, "stack 3 has run out of crates");
+        assert_eq!(err.line, Some(input.len()));
+        assert_eq!(err.text, "move 5 from 3 to 1");
+    }
+
+    #[test]
+    fn trailing_spaces_do_not_matter() {
+        let filename = "input/day05_sample.txt";
+        let options = ReadOptions {
+            trailing_whitespace: false,
+            ..ReadOptions::default()
+        };
+        let untrimmed = file_to_vec_with(filename, options).unwrap();
+        let trimmed = file_to_vec(filename).unwrap();
+        assert_ne!(untrimmed, trimmed);
+        assert_eq!(part_1(&untrimmed).unwrap(), part_1(&trimmed).unwrap());
+    }
+
+    #[test]
+    fn extra_blank_lines_are_skipped() {
+        let filename = "input/day05_sample.txt";
+        let mut input = file_to_vec(filename).unwrap();
+        input.insert(0, String::new());
+        input.insert(5, String::new());
+        input.push(String::new());
+        assert_eq!(part_1(&input).unwrap(), "CMZ");
+
+        input.insert(7, String::new());
+        input.insert(8, "move 1 from 1 to 2".to_owned());
+        assert!(part_1(&input).is_err());
+    }
+
+    #[test]
+    fn animate_sample() {
+        let input = file_to_vec("input/day05_sample.txt").unwrap();
+        for (part, tops) in [(Part::One, "CMZ"), (Part::Two, "MCD")] {
+            let mut animation = CrateAnimation::new(part, &input).unwrap();
+            assert_eq!(animation.height, 4);
+            while animation.step().unwrap() {}
+            let frame = animation.frame();
+            assert!(frame.caption.ends_with(tops), "{}", frame.caption);
+        }
+        let frame = CrateAnimation::new(Part::One, &input).unwrap().frame();
+        let text: String = frame
+            .cells
+            .rows()
+            .nth(3)
+            .unwrap()
+            .iter()
+            .map(|c| c.symbol)
+            .collect();
+        assert_eq!(text, "[Z] [M] [P]");
+    }
+}
@@ -0,0 +1,138 @@
+// https://adventofcode.com/2022/day/6
+
+use crate::runner::Part;
+use crate::{Answer, AocError, Solution};
+use itertools::Itertools;
+use std::collections::VecDeque;
+use std::io::{BufRead, Read};
+
+const START_OF_PACKET_LENGTH: usize = 4;
+const START_OF_MESSAGE_LENGTH: usize = 14;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn stream_bytes(&self, part: Part, input: &mut dyn BufRead) -> Option<anyhow::Result<Answer>> {
+        Some(stream_marker(part, input).map(Answer::from))
+    }
+}
+
+/// Read the datastream a byte at a time, only keeping the last few, until the marker is found
+fn stream_marker(part: Part, input: &mut dyn BufRead) -> anyhow::Result<usize> {
+    let n_unique = match part {
+        Part::One => START_OF_PACKET_LENGTH,
+        Part::Two => START_OF_MESSAGE_LENGTH,
+    };
+    let mut window = VecDeque::with_capacity(n_unique);
+    for (idx, byte) in Read::bytes(input).enumerate() {
+        let byte = byte?;
+        // Input is a single line
+        if byte == b'\n' || byte == b'\r' {
+            break;
+        }
+        if !byte.is_ascii() {
+            let err = AocError::input("unexpected character").at_line(0);
+            return Err(err.at_column(idx).into());
+        }
+        if window.len() == n_unique {
+            window.pop_front();
+        }
+        window.push_back(byte);
+        if window.len() == n_unique && window.iter().all_unique() {
+            return Ok(idx + 1);
+        }
+    }
+    let message = format!("no {} characters in a row are all different", n_unique);
+    Err(AocError::input(message).at_line(0).into())
+}
+
+fn part_1(input: &[String]) -> Result<usize, AocError> {
+    let line = get_datastream(input)?;
+    get_first_index_with_all_unique(line, START_OF_PACKET_LENGTH)
+}
+
+fn get_datastream(input: &[String]) -> Result<&str, AocError> {
+    // Input is a single line
+    let line = input
+        .first()
+        .ok_or_else(|| AocError::input("the input is empty"))?;
+    // The datastream is sliced by bytes, so every char must be a single byte
+    match line.chars().position(|c| !c.is_ascii()) {
+        Some(column) => Err(AocError::new(0, line, "unexpected character").at_column(column)),
+        None => Ok(line),
+    }
+}
+
+fn get_first_index_with_all_unique(line: &str, n_unique: usize) -> Result<usize, AocError> {
+    for index in n_unique..=line.len() {
+        let slice = &line[index - n_unique..index];
+        if is_all_unique(slice) {
+            return Ok(index);
+        }
+    }
+    let message = format!("no {} characters in a row are all different", n_unique);
+    Err(AocError::new(0, line, message))
+}
+
+fn is_all_unique(slice: &str) -> bool {
+    let n_chars = slice.len();
+    let n_unique_chars = slice.chars().unique().count();
+    n_chars == n_unique_chars
+}
+
+fn part_2(input: &[String]) -> Result<usize, AocError> {
+    let line = get_datastream(input)?;
+    get_first_index_with_all_unique(line, START_OF_MESSAGE_LENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[test]
+    fn marker_at_the_end() {
+        assert_eq!(get_first_index_with_all_unique("aaabcd", 4), Ok(6));
+        assert!(get_first_index_with_all_unique("aaabca", 4).is_err());
+    }
+
+    #[test]
+    fn empty_input_is_an_error() {
+        assert!(part_1(&[]).is_err());
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let mut sample = BufReader::new(File::open("input/day06_sample.txt").unwrap());
+        let result = Day06.stream_bytes(Part::One, &mut sample).unwrap().unwrap();
+        assert_eq!(result, Answer::Int(7));
+    }
+
+    #[test]
+    fn stream_bad_input() {
+        let mut input: &[u8] = b"abcabc\nabcd\n";
+        let err = stream_marker(Part::One, &mut input).unwrap_err();
+        assert!(err.to_string().contains("no 4 characters"), "{}", err);
+        let mut input: &[u8] = b"ab\xffcd";
+        let err = stream_marker(Part::One, &mut input).unwrap_err();
+        let err = err.downcast::<AocError>().unwrap();
+        assert_eq!((err.line, err.column), (Some(1), Some(3)));
+    }
+}
@@ -0,0 +1,185 @@
+// https://adventofcode.com/2022/day/7
+
+use crate::parse::{int, literal, parse_line, preceded, word, Cursor};
+use crate::runner::Part;
+use crate::{Answer, AocError, Solution, Solver};
+
+#[derive(Debug)]
+struct Dir {
+    name: String,
+    parent: usize,
+    size: usize,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        let tree = create_dir_tree(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || Ok(sum_small_dirs(&tree).into())),
+            Part::Two => Box::new(move || Ok(find_dir_to_delete(&tree)?.into())),
+        })
+    }
+}
+
+fn part_1(input: &[String]) -> Result<usize, AocError> {
+    let tree = create_dir_tree(input)?;
+    Ok(sum_small_dirs(&tree))
+}
+
+fn sum_small_dirs(tree: &[Dir]) -> usize {
+    // Calculate size of dirs that have at most size = threshold
+    let threshold = 100_000;
+    tree.iter()
+        .filter(|dir| dir.size <= threshold)
+        .fold(0, |acc, dir| acc + dir.size)
+}
+
+fn create_dir_tree(input: &[String]) -> Result<Vec<Dir>, AocError> {
+    match input.first() {
+        Some(line) if line == "$ cd /" => {}
+        Some(line) => return Err(AocError::new(0, line, "expected to start with '$ cd /'")),
+        None => return Err(AocError::input("the input is empty")),
+    }
+    let mut tree: Vec<Dir> = vec![Dir {
+        name: "/".to_string(),
+        parent: 0,
+        size: 0,
+    }];
+    let mut current_dir_idx = 0;
+    // I'm adding the first line manually to make sure we have an initial element in the list
+    for (idx, line) in input.iter().enumerate().skip(1) {
+        if line.starts_with("$ cd") {
+            let name = parse_line(line, preceded(literal("$ cd "), word()))
+                .map_err(|err| err.in_line(idx, line))?
+                .to_owned();
+            if name == "/" {
+                current_dir_idx = 0;
+            } else if name == ".." {
+                // move up one level.
+                current_dir_idx = tree.get(current_dir_idx).unwrap().parent;
+            } else {
+                // Add a new folder to the tree and move there
+                // I'm assuming we only visit each folder once.
+                tree.push(Dir {
+                    name,
+                    parent: current_dir_idx,
+                    size: 0,
+                });
+                current_dir_idx = tree.len() - 1;
+            }
+        } else if line == "$ ls" || line.starts_with("dir ") {
+            // in these cases I dont care
+        } else if line.starts_with('$') {
+            return Err(AocError::new(idx, line, "unknown command").at_column(2));
+        } else {
+            // this is a file
+            let size = parse_line(line, |c: &mut Cursor| {
+                let size = c.parse(int())?;
+                c.parse(literal(" "))?;
+                c.parse(word())?;
+                Ok(size)
+            })
+            .map_err(|err| err.in_line(idx, line))?;
+            tree = update_sizes(current_dir_idx, size, tree).ok_or_else(|| {
+                AocError::new(idx, line, "the directory is too big to count its size")
+            })?;
+        }
+        // println!("{line}");
+        // println!("Current dir: {:?}", &tree.get(current_dir_idx).unwrap());
+        // println!("{:?}", tree);
+        // println!("--");
+    }
+    Ok(tree)
+}
+
+/// Add `size` to the directory and all its parents, or `None` if a size overflows
+fn update_sizes(current_dir_idx: usize, size: usize, mut tree: Vec<Dir>) -> Option<Vec<Dir>> {
+    let mut update_idx = current_dir_idx;
+    loop {
+        let current_dir = tree.get_mut(update_idx).unwrap();
+        current_dir.size = current_dir.size.checked_add(size)?;
+        update_idx = current_dir.parent;
+        // If we have updated the root node, stop
+        if current_dir.name == "/" {
+            break;
+        }
+    }
+    Some(tree)
+}
+
+fn part_2(input: &[String]) -> Result<usize, AocError> {
+    let tree = create_dir_tree(input)?;
+    find_dir_to_delete(&tree)
+}
+
+fn find_dir_to_delete(tree: &[Dir]) -> Result<usize, AocError> {
+    let total_diskspace = 70000000;
+    let needed_diskspace: usize = 30000000;
+    let used_diskspace = tree[0].size;
+    if used_diskspace > total_diskspace {
+        return Err(AocError::input("the files are larger than the disk"));
+    }
+    let available_diskspace = total_diskspace - used_diskspace;
+    let space_to_delete = needed_diskspace.saturating_sub(available_diskspace);
+    // println!("need to delete at least {space_to_delete}");
+    // Find the dir I have to delete. It has to be above the threshold, but only as little above as possible.
+    let dir_to_delete = tree
+        .iter()
+        .filter(|dir| dir.size >= space_to_delete)
+        .min_by_key(|dir| space_to_delete.abs_diff(dir.size))
+        .ok_or_else(|| AocError::input("no directory is large enough to delete"))?;
+    // println!("deleting {:?}", dir_to_delete);
+    Ok(dir_to_delete.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_terminal_output_is_an_error() {
+        let input = vec![
+            "$ cd /".to_owned(),
+            "$ ls".to_owned(),
+            "big a.txt".to_owned(),
+        ];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!(err.line, Some(3));
+
+        let input = vec!["$ cd /".to_owned(), "$ rm -rf".to_owned()];
+        assert!(part_1(&input).is_err());
+
+        let input = vec!["$ ls".to_owned()];
+        assert!(part_1(&input).is_err());
+    }
+
+    #[test]
+    fn huge_files_are_an_error() {
+        let input = vec![
+            "$ cd /".to_owned(),
+            "18446744073709551615 f".to_owned(),
+            "4969 d.log".to_owned(),
+        ];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (Some(3), "4969 d.log"));
+        assert!(part_2(&input).is_err());
+    }
+}
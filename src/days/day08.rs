@@ -0,0 +1,285 @@
+// https://adventofcode.com/2022/day/8
+
+use crate::grid::{Pos, Step, STEPS_4};
+use crate::runner::Part;
+use crate::visualize::{Frame, Style, Visualize};
+use crate::{Answer, AocError, Grid, Solution, Solver};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn part_1(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> anyhow::Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> anyhow::Result<Solver<'a>> {
+        let tree_heights = get_tree_heights(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || Ok(count_visible_trees(&tree_heights).into())),
+            Part::Two => Box::new(move || Ok(get_best_tree_score(&tree_heights).into())),
+        })
+    }
+
+    fn visualize(
+        &self,
+        part: Part,
+        input: &[String],
+    ) -> Option<anyhow::Result<Box<dyn Visualize>>> {
+        let survey = TreeSurvey::new(part, input);
+        Some(
+            survey
+                .map(|survey| Box::new(survey) as _)
+                .map_err(Into::into),
+        )
+    }
+}
+
+fn part_1(input: &[String]) -> Result<usize, AocError> {
+    let tree_heights: Grid<u32> = get_tree_heights(input)?;
+    Ok(count_visible_trees(&tree_heights))
+}
+
+fn count_visible_trees(tree_heights: &Grid<u32>) -> usize {
+    tree_heights
+        .positions()
+        .filter(|&pos| is_tree_visible(tree_heights, pos))
+        .count()
+}
+
+/// Parse the map of tree heights, which must be a non-empty rectangle of digits
+fn get_tree_heights(input: &[String]) -> Result<Grid<u32>, AocError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+/// Whether every tree between `pos` and one of the edges is lower
+fn is_tree_visible(tree_heights: &Grid<u32>, pos: Pos) -> bool {
+    let this_height = tree_heights[pos];
+    STEPS_4.into_iter().any(|step| {
+        tree_heights
+            .ray(pos, step)
+            .all(|other| tree_heights[other] < this_height)
+    })
+}
+
+fn part_2(input: &[String]) -> Result<usize, AocError> {
+    let tree_heights: Grid<u32> = get_tree_heights(input)?;
+    Ok(get_best_tree_score(&tree_heights))
+}
+
+fn get_best_tree_score(tree_heights: &Grid<u32>) -> usize {
+    tree_heights
+        .positions()
+        .map(|pos| get_tree_score(tree_heights, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+fn get_tree_score(tree_heights: &Grid<u32>, pos: Pos) -> usize {
+    STEPS_4
+        .into_iter()
+        .map(|step| view(tree_heights, pos, step).count())
+        .product()
+}
+
+/// The trees that can be seen from `pos` looking along `step`, up to and including the first one
+/// that blocks the view
+fn view(tree_heights: &Grid<u32>, pos: Pos, step: Step) -> impl Iterator<Item = Pos> + '_ {
+    let this_height = tree_heights[pos];
+    let mut blocked = false;
+    tree_heights.ray(pos, step).take_while(move |&other| {
+        let seen = !blocked;
+        blocked |= tree_heights[other] >= this_height;
+        seen
+    })
+}
+
+/// Checking the trees one at a time, row by row
+struct TreeSurvey {
+    part: Part,
+    tree_heights: Grid<u32>,
+    n_checked: usize,
+    /// Whether each tree that has been checked is visible from outside the grid
+    visible: Grid<bool>,
+    n_visible: usize,
+    /// The best scenic score so far, and where it is
+    best: Option<(usize, Pos)>,
+}
+
+impl TreeSurvey {
+    fn new(part: Part, input: &[String]) -> Result<Self, AocError> {
+        let tree_heights = get_tree_heights(input)?;
+        let visible = tree_heights.map(|_| false);
+        Ok(TreeSurvey {
+            part,
+            tree_heights,
+            n_checked: 0,
+            visible,
+            n_visible: 0,
+            best: None,
+        })
+    }
+
+    fn pos(&self, idx: usize) -> Pos {
+        (
+            idx / self.tree_heights.width(),
+            idx % self.tree_heights.width(),
+        )
+    }
+}
+
+impl Visualize for TreeSurvey {
+    fn frame(&self) -> Frame {
+        let heights = &self.tree_heights;
+        let mut frame = Frame::new(heights.width(), heights.height());
+        let current = self.n_checked.checked_sub(1).map(|idx| self.pos(idx));
+        let in_view: Vec<Pos> = match (self.part, current) {
+            (Part::Two, Some(pos)) => STEPS_4
+                .into_iter()
+                .flat_map(|step| view(heights, pos, step))
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (idx, pos) in heights.positions().enumerate() {
+            let style = if Some(pos) == current {
+                Style::Highlight
+            } else if self.part == Part::Two {
+                if in_view.contains(&pos) {
+                    Style::Bright
+                } else {
+                    Style::Dim
+                }
+            } else if idx >= self.n_checked {
+                Style::Normal
+            } else if self.visible[pos] {
+                Style::Bright
+            } else {
+                Style::Dim
+            };
+            let symbol = char::from_digit(heights[pos], 10).unwrap_or('?');
+            frame.write(pos, &symbol.to_string(), style);
+        }
+        frame.focus = current;
+        frame.caption = match (self.part, current) {
+            (_, None) => format!("{} trees to check", heights.width() * heights.height()),
+            (Part::One, Some((row, column))) => format!(
+                "Row {}, column {}: {} of {} trees checked are visible",
+                row + 1,
+                column + 1,
+                self.n_visible,
+                self.n_checked
+            ),
+            (Part::Two, Some(pos)) => {
+                let (best, (row, column)) = self.best.unwrap_or((0, pos));
+                format!(
+                    "Row {}, column {}: score {}, best so far {} at row {}, column {}",
+                    pos.0 + 1,
+                    pos.1 + 1,
+                    get_tree_score(heights, pos),
+                    best,
+                    row + 1,
+                    column + 1
+                )
+            }
+        };
+        frame
+    }
+
+    fn step(&mut self) -> anyhow::Result<bool> {
+        if self.n_checked == self.tree_heights.width() * self.tree_heights.height() {
+            return Ok(false);
+        }
+        let pos = self.pos(self.n_checked);
+        self.n_checked += 1;
+        match self.part {
+            Part::One => {
+                let visible = is_tree_visible(&self.tree_heights, pos);
+                self.visible[pos] = visible;
+                self.n_visible += usize::from(visible);
+            }
+            Part::Two => {
+                let score = get_tree_score(&self.tree_heights, pos);
+                if self.best.is_none_or(|(best, _)| score > best) {
+                    self.best = Some((score, pos));
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_to_vec;
+
+    #[test]
+    fn test_score_row_3_col_2() {
+        let filename = "input/day08_sample.txt";
+        let input = file_to_vec(filename).unwrap();
+
+        let tree_heights: Grid<u32> = get_tree_heights(&input).unwrap();
+
+        let score = get_tree_score(&tree_heights, (3, 2));
+        assert_eq!(score, 8)
+    }
+
+    #[test]
+    fn test_score_row_1_col_2() {
+        let filename = "input/day08_sample.txt";
+        let input = file_to_vec(filename).unwrap();
+
+        let tree_heights: Grid<u32> = get_tree_heights(&input).unwrap();
+
+        let score = get_tree_score(&tree_heights, (1, 2));
+        assert_eq!(score, 4)
+    }
+    #[test]
+    fn test_score_row_2_col_2() {
+        let filename = "input/day08_sample.txt";
+        let input = file_to_vec(filename).unwrap();
+
+        let tree_heights: Grid<u32> = get_tree_heights(&input).unwrap();
+
+        let score = get_tree_score(&tree_heights, (2, 2));
+        assert_eq!(score, 1)
+    }
+
+    #[test]
+    fn bad_tree_map_is_an_error() {
+        let input = vec!["303".to_owned(), "2x5".to_owned()];
+        let err = part_1(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+
+        let input = vec!["303".to_owned(), "25".to_owned()];
+        assert!(part_2(&input).is_err());
+        assert!(part_2(&[]).is_err());
+    }
+
+    #[test]
+    fn survey_sample() {
+        let input = file_to_vec("input/day08_sample.txt").unwrap();
+        let mut survey = TreeSurvey::new(Part::One, &input).unwrap();
+        while survey.step().unwrap() {}
+        assert_eq!(survey.n_visible, 21);
+        assert!(survey
+            .frame()
+            .caption
+            .ends_with("21 of 25 trees checked are visible"));
+
+        let mut survey = TreeSurvey::new(Part::Two, &input).unwrap();
+        while survey.step().unwrap() {}
+        assert_eq!(survey.best, Some((8, (3, 2))));
+    }
+}
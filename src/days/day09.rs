@@ -0,0 +1,407 @@
+// https://adventofcode.com/2022/day/9
+
+use crate::geometry::{BoundingBox, Direction, Point2};
+use crate::grid::Pos;
+use crate::runner::Part;
+use crate::visualize::{window, Frame, Style, Visualize};
+use crate::{Answer, AocError, Solution, Solver};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+struct Move {
+    direction: Direction,
+    length: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct SingleMove {
+    direction: Direction,
+}
+impl SingleMove {
+    fn from_move(m: &Move) -> Vec<Self> {
+        vec![
+            SingleMove {
+                direction: m.direction
+            };
+            m.length
+        ]
+    }
+}
+
+impl FromStr for Move {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (d, l) = s
+            .split_once(' ')
+            .ok_or_else(|| AocError::in_text(s, "expected a direction and a length, like 'R 4'"))?;
+
+        let direction = match d {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => {
+                let message = "the direction must be one of U, D, L or R";
+                return Err(AocError::in_text(s, message).at_column(0));
+            }
+        };
+        let length = l.parse().map_err(|err| {
+            let message = format!("bad length: {}", err);
+            AocError::in_text(s, message).at_column(d.chars().count() + 1)
+        })?;
+        Ok(Move { direction, length })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn part_1(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> Result<Solver<'a>> {
+        let moves = parse_input_to_moves(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || {
+                let positions = move_and_record_positions(&moves);
+                Ok(positions.into_iter().counts().len().into())
+            }),
+            Part::Two => Box::new(move || {
+                let positions = move_and_record_positions_long_rope(&moves);
+                Ok(positions.into_iter().counts().len().into())
+            }),
+        })
+    }
+
+    fn visualize(&self, part: Part, input: &[String]) -> Option<Result<Box<dyn Visualize>>> {
+        let animation = RopeAnimation::new(part, input);
+        Some(
+            animation
+                .map(|animation| Box::new(animation) as _)
+                .map_err(Into::into),
+        )
+    }
+}
+
+fn part_1(input: &[String]) -> Result<usize, AocError> {
+    let moves = parse_input_to_moves(input)?;
+    let positions = move_and_record_positions(&moves);
+    Ok(positions.into_iter().counts().len())
+}
+
+/// Every step of the rope is kept in memory, so longer walks are rejected rather than running out
+const MAX_STEPS: usize = 10_000_000;
+
+fn parse_input_to_moves(input: &[String]) -> Result<Vec<SingleMove>, AocError> {
+    let moves: Vec<Move> = input
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| Move::from_str(line).map_err(|err| err.at_line(idx)))
+        .collect::<Result<_, _>>()?;
+    let mut n_steps: usize = 0;
+    for (idx, m) in moves.iter().enumerate() {
+        n_steps = n_steps
+            .checked_add(m.length)
+            .filter(|&n_steps| n_steps <= MAX_STEPS)
+            .ok_or_else(|| {
+                let message = format!("the rope moves more than {} steps", MAX_STEPS);
+                AocError::new(idx, &input[idx], message)
+            })?;
+    }
+    let single_moves: Vec<SingleMove> = moves
+        .into_iter()
+        .flat_map(|m| SingleMove::from_move(&m))
+        .collect();
+    Ok(single_moves)
+}
+
+fn move_and_record_positions(moves: &[SingleMove]) -> Vec<Point2> {
+    let mut output = Vec::new();
+    let mut head_position = Point2::ORIGIN;
+    let mut tail_position = Point2::ORIGIN;
+    output.push(tail_position);
+    for &m in moves {
+        head_position = move_head(head_position, m);
+        tail_position = move_tail(head_position, tail_position);
+        output.push(tail_position);
+    }
+    output
+}
+
+fn move_tail(head_position: Point2, tail_position: Point2) -> Point2 {
+    if tail_position.is_touching(head_position) {
+        tail_position
+    } else {
+        tail_position.step_towards(head_position)
+    }
+}
+
+fn move_head(head_position: Point2, single_move: SingleMove) -> Point2 {
+    head_position + single_move.direction.unit()
+}
+
+#[allow(clippy::needless_range_loop)]
+fn move_and_record_positions_long_rope(moves: &[SingleMove]) -> Vec<Point2> {
+    let mut output = Vec::new();
+    let mut head_position = Point2::ORIGIN;
+    let mut tail_positions = [Point2::ORIGIN; 9];
+    output.push(tail_positions[8]);
+    for &m in moves {
+        head_position = move_head(head_position, m);
+        let mut tmp_head = head_position;
+        for i in 0..9 {
+            tmp_head = move_tail(tmp_head, tail_positions[i]);
+            tail_positions[i] = tmp_head;
+        }
+        output.push(tail_positions[8]);
+    }
+    output
+}
+
+fn part_2(input: &[String]) -> Result<usize, AocError> {
+    let moves = parse_input_to_moves(input)?;
+    let positions: Vec<Point2> = move_and_record_positions_long_rope(&moves);
+    Ok(positions.into_iter().counts().len())
+}
+
+/// The most cells a frame of [`RopeAnimation`] shows. Longer walks are followed around the head,
+/// so a frame doesn't grow with the walk.
+const VIEW_WIDTH: usize = 80;
+const VIEW_HEIGHT: usize = 40;
+
+/// The rope moving one step at a time
+struct RopeAnimation {
+    moves: Vec<SingleMove>,
+    n_done: usize,
+    /// The head first
+    knots: Vec<Point2>,
+    visited: HashSet<Point2>,
+    /// Every knot follows the head, so they all stay in the box around its path
+    bounds: BoundingBox,
+}
+
+impl RopeAnimation {
+    fn new(part: Part, input: &[String]) -> Result<Self, AocError> {
+        let moves = parse_input_to_moves(input)?;
+        let mut head_position = Point2::ORIGIN;
+        let mut bounds = BoundingBox::new(head_position);
+        for &m in &moves {
+            head_position = move_head(head_position, m);
+            bounds.include(head_position);
+        }
+        let n_knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        Ok(RopeAnimation {
+            moves,
+            n_done: 0,
+            knots: vec![Point2::ORIGIN; n_knots],
+            visited: HashSet::from([Point2::ORIGIN]),
+            bounds,
+        })
+    }
+}
+
+impl Visualize for RopeAnimation {
+    fn frame(&self) -> Frame {
+        // Where a point is in the box around the whole walk
+        let cell = |point: Point2| {
+            (
+                (self.bounds.max.y - point.y) as usize,
+                (point.x - self.bounds.min.x) as usize,
+            )
+        };
+        let head = cell(self.knots[0]);
+        let rows = window(head.0, VIEW_HEIGHT, self.bounds.height() as usize);
+        let columns = window(head.1, VIEW_WIDTH, self.bounds.width() as usize);
+        let mut frame = Frame::new(columns.len(), rows.len());
+        // Where a point is in the frame, if it is in view
+        let in_view = |point: Point2| -> Option<Pos> {
+            let (row, column) = cell(point);
+            (rows.contains(&row) && columns.contains(&column))
+                .then(|| (row - rows.start, column - columns.start))
+        };
+        for row in rows.clone() {
+            for column in columns.clone() {
+                let x = self.bounds.min.x + column as i32;
+                let y = self.bounds.max.y - row as i32;
+                if self.visited.contains(&Point2::new(x, y)) {
+                    frame.write((row - rows.start, column - columns.start), "#", Style::Dim);
+                }
+            }
+        }
+        if let Some(pos) = in_view(Point2::ORIGIN) {
+            frame.write(pos, "s", Style::Normal);
+        }
+        // From the tail to the head, so knots in front are drawn on top
+        for (idx, &knot) in self.knots.iter().enumerate().rev() {
+            let (label, style) = match idx {
+                0 => ('H', Style::Highlight),
+                _ if self.knots.len() == 2 => ('T', Style::Bright),
+                _ => (
+                    char::from_digit(idx as u32, 10).unwrap_or('?'),
+                    Style::Bright,
+                ),
+            };
+            if let Some(pos) = in_view(knot) {
+                frame.write(pos, &label.to_string(), style);
+            }
+        }
+        frame.focus = in_view(self.knots[0]);
+        frame.caption = format!(
+            "Step {} of {}, the tail has visited {} positions",
+            self.n_done,
+            self.moves.len(),
+            self.visited.len()
+        );
+        frame
+    }
+
+    fn step(&mut self) -> Result<bool> {
+        let Some(&m) = self.moves.get(self.n_done) else {
+            return Ok(false);
+        };
+        self.knots[0] = move_head(self.knots[0], m);
+        for idx in 1..self.knots.len() {
+            self.knots[idx] = move_tail(self.knots[idx - 1], self.knots[idx]);
+        }
+        self.visited.extend(self.knots.last());
+        self.n_done += 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_to_moves() {
+        let input = vec!["R 4".to_owned(), "D 2".to_owned()];
+        let result = parse_input_to_moves(&input).unwrap();
+
+        let mut expected = vec![
+            SingleMove {
+                direction: Direction::Right,
+            };
+            4
+        ];
+        expected.extend(vec![
+            SingleMove {
+                direction: Direction::Down
+            };
+            2
+        ]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_bad_moves() {
+        let input = vec!["R 4".to_owned(), "X 2".to_owned()];
+        let err = parse_input_to_moves(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+
+        let input = vec!["R four".to_owned()];
+        let err = parse_input_to_moves(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(3)));
+
+        let input = vec!["R 2147483647".to_owned()];
+        let err = parse_input_to_moves(&input).unwrap_err();
+        assert_eq!(err.line, Some(1));
+        let input = vec!["R 4".to_owned(), "L 18446744073709551615".to_owned()];
+        let err = parse_input_to_moves(&input).unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_move_tail() {
+        let head_pos = Point2::new(10, 12);
+        let tail_pos = Point2::new(10, 10);
+        let result = move_tail(head_pos, tail_pos);
+        let expected = Point2::new(10, 11);
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_move_and_record_positions() {
+        let mut input = vec![
+            SingleMove {
+                direction: Direction::Up
+            };
+            3
+        ];
+        input.extend(vec![
+            SingleMove {
+                direction: Direction::Right
+            };
+            3
+        ]);
+        let result = move_and_record_positions(&input);
+        let expected = vec![
+            Point2::new(0, 0), // initial
+            Point2::new(0, 0), // U, head at 0,1
+            Point2::new(0, 1), // U, head at 0,2
+            Point2::new(0, 2), // U, head at 0,3
+            Point2::new(0, 2), // R, head at 1,3
+            Point2::new(1, 3), // R, head at 2,3
+            Point2::new(2, 3), // R, head at 3,3
+        ];
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn animate_sample() {
+        let input = crate::file_to_vec("input/day09_sample.txt").unwrap();
+        for (part, n_visited) in [(Part::One, 13), (Part::Two, 1)] {
+            let mut animation = RopeAnimation::new(part, &input).unwrap();
+            while animation.step().unwrap() {}
+            assert_eq!(animation.visited.len(), n_visited);
+        }
+
+        let mut animation = RopeAnimation::new(Part::One, &input).unwrap();
+        animation.step().unwrap();
+        let frame = animation.frame();
+        assert_eq!((frame.cells.width(), frame.cells.height()), (6, 5));
+        let bottom: String = frame.cells.row(4).iter().map(|cell| cell.symbol).collect();
+        assert_eq!(bottom, "TH    ");
+    }
+
+    #[test]
+    fn long_walks_are_shown_around_the_head() {
+        let input = vec!["R 1000".to_owned(), "U 500".to_owned()];
+        let mut animation = RopeAnimation::new(Part::One, &input).unwrap();
+        while animation.step().unwrap() {}
+        let frame = animation.frame();
+        assert_eq!(
+            (frame.cells.width(), frame.cells.height()),
+            (VIEW_WIDTH, VIEW_HEIGHT)
+        );
+        // The head is in the top right corner of the walk, so it ends up in that of the frame
+        assert_eq!(frame.focus, Some((0, VIEW_WIDTH - 1)));
+        let column: String = frame
+            .cells
+            .column(VIEW_WIDTH - 1)
+            .map(|cell| cell.symbol)
+            .collect();
+        assert_eq!(column, format!("HT{}", "#".repeat(VIEW_HEIGHT - 2)));
+    }
+}
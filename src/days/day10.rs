@@ -0,0 +1,339 @@
+// https://adventofcode.com/2022/day/10
+
+use crate::parse::{int, literal, parse_line, preceded};
+use crate::runner::Part;
+use crate::visualize::{Frame, Style, Visualize};
+use crate::{Answer, AocError, LineStream, Solution, Solver};
+use anyhow::Result;
+use itertools::{enumerate, Itertools};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn part_1(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> Result<Solver<'a>> {
+        let commands = extract_commands_for_x(input)?;
+        Ok(match part {
+            Part::One => Box::new(move || Ok(sum_signal_strengths(&commands)?.into())),
+            Part::Two => Box::new(move || Ok(draw_crt(&commands)?.into())),
+        })
+    }
+
+    fn stream(&self, part: Part, lines: &mut LineStream) -> Option<Result<Answer>> {
+        Some(stream_crt(part, lines))
+    }
+
+    /// Both parts show the same program, with the signal strength and the screen
+    fn visualize(&self, _part: Part, input: &[String]) -> Option<Result<Box<dyn Visualize>>> {
+        let animation = CrtAnimation::new(input);
+        Some(
+            animation
+                .map(|animation| Box::new(animation) as _)
+                .map_err(Into::into),
+        )
+    }
+}
+
+/// Run the program one instruction at a time, only keeping the screen in memory
+fn stream_crt(part: Part, lines: &mut LineStream) -> Result<Answer> {
+    let mut commands = Vec::new();
+    let mut x = 1;
+    let mut cycle = 0;
+    let mut signal_strength = 0;
+    let mut drawing = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        commands.clear();
+        push_commands(idx, &line?, &mut commands)?;
+        for command in &commands {
+            // The pixel is drawn with X during the cycle, before the command changes it
+            if part == Part::Two {
+                drawing.push(get_pixel(cycle, x)?);
+            }
+            // Same as the cumulative values in the functions below
+            x = add_to_x(x, *command)?;
+            if (cycle + 2) % 40 == 20 {
+                signal_strength = add_signal_strength(signal_strength, cycle + 2, x)?;
+            }
+            cycle += 1;
+        }
+    }
+    Ok(match part {
+        Part::One => signal_strength.into(),
+        Part::Two => join_rows(&drawing).into(),
+    })
+}
+
+fn part_1(input: &[String]) -> Result<i32> {
+    let commands = extract_commands_for_x(input)?;
+    sum_signal_strengths(&commands)
+}
+
+fn sum_signal_strengths(commands: &[i32]) -> Result<i32> {
+    let x_val = get_cumulative_values_for_x(commands)?;
+    let signal_strengths = get_signal_strengths(x_val)?;
+    let sum = signal_strengths
+        .into_iter()
+        .try_fold(0i32, i32::checked_add);
+    Ok(sum.ok_or_else(|| AocError::input("the sum of the signal strengths overflows"))?)
+}
+
+fn add_to_x(x: i32, change: i32) -> Result<i32, AocError> {
+    x.checked_add(change)
+        .ok_or_else(|| AocError::input("the X register overflows"))
+}
+
+/// Add the signal strength during `cycle` to `sum`
+fn add_signal_strength(sum: i32, cycle: usize, x: i32) -> Result<i32> {
+    let sum = x
+        .checked_mul(i32::try_from(cycle)?)
+        .and_then(|strength| sum.checked_add(strength));
+    Ok(sum.ok_or_else(|| AocError::input("the sum of the signal strengths overflows"))?)
+}
+
+fn extract_commands_for_x(input: &[String]) -> Result<Vec<i32>, AocError> {
+    let mut commands = Vec::new();
+    for (idx, line) in input.iter().enumerate() {
+        push_commands(idx, line, &mut commands)?;
+    }
+    Ok(commands)
+}
+
+/// Push how much the instruction on `line` changes X in each of the cycles it takes
+fn push_commands(idx: usize, line: &str, commands: &mut Vec<i32>) -> Result<(), AocError> {
+    if line == "noop" {
+        commands.push(0);
+    } else if line.starts_with("addx") {
+        commands.push(0);
+        let x = parse_line(line, preceded(literal("addx "), int()))
+            .map_err(|err| err.in_line(idx, line))?;
+        commands.push(x);
+    } else {
+        return Err(AocError::new(idx, line, "expected 'noop' or 'addx'"));
+    }
+    Ok(())
+}
+
+fn get_cumulative_values_for_x(x_val: &[i32]) -> Result<Vec<i32>> {
+    let mut prev = 1;
+    let mut cumsum = Vec::new();
+    for &el in x_val {
+        prev = add_to_x(prev, el)?;
+        cumsum.push(prev);
+    }
+    Ok(cumsum)
+}
+
+fn get_signal_strengths(cumsum: Vec<i32>) -> Result<Vec<i32>> {
+    let interesting_indicies = (20..).step_by(40);
+    let mut result = Vec::new();
+    for idx in interesting_indicies {
+        let register = cumsum.get(idx - 2);
+        match register {
+            Some(val) => result.push(add_signal_strength(0, idx, *val)?),
+            None => break,
+        }
+    }
+    Ok(result)
+}
+
+fn part_2(input: &[String]) -> Result<String> {
+    let commands = extract_commands_for_x(input)?;
+    draw_crt(&commands)
+}
+
+/// Draw the CRT screen, with one line of text per row of pixels
+fn draw_crt(commands: &[i32]) -> Result<String> {
+    let x_val = get_cumulative_values_for_x(commands)?;
+    let lines = get_line_drawing(x_val)?;
+    Ok(join_rows(&lines))
+}
+
+fn join_rows(drawing: &[String]) -> String {
+    drawing.chunks(40).map(|row| row.concat()).join("\n")
+}
+
+/// `x_val` is X after each cycle, so X during a cycle is the value after the one before, and 1
+/// during the first
+fn get_line_drawing(x_val: Vec<i32>) -> Result<Vec<String>> {
+    let mut drawing = Vec::new();
+    let during = std::iter::once(1).chain(x_val.iter().copied());
+    for (cycle, value) in enumerate(during).take(x_val.len()) {
+        drawing.push(get_pixel(cycle, value)?);
+    }
+    Ok(drawing)
+}
+
+/// Lit when the sprite, three pixels wide around X, covers the pixel being drawn
+fn get_pixel(cycle: usize, value: i32) -> Result<String> {
+    let mod_cycle = cycle % 40;
+    // In an i64, so an X far off the screen can't overflow
+    if (i64::try_from(mod_cycle)? - i64::from(value)).abs() < 2 {
+        Ok("#".to_string())
+    } else {
+        Ok(".".to_string())
+    }
+}
+
+/// The program running one cycle at a time, with the CRT drawing as it goes
+struct CrtAnimation {
+    /// How much X changes in each cycle
+    commands: Vec<i32>,
+    cycle: usize,
+    x: i32,
+    signal_strength: i32,
+    drawing: Vec<String>,
+}
+
+/// The rows above the screen, for the sprite and a gap
+const SPRITE_ROWS: usize = 2;
+
+impl CrtAnimation {
+    fn new(input: &[String]) -> Result<Self, AocError> {
+        Ok(CrtAnimation {
+            commands: extract_commands_for_x(input)?,
+            cycle: 0,
+            x: 1,
+            signal_strength: 0,
+            drawing: Vec::new(),
+        })
+    }
+}
+
+impl Visualize for CrtAnimation {
+    fn frame(&self) -> Frame {
+        let n_rows = self.commands.len().div_ceil(40).max(6);
+        let mut frame = Frame::new(40, SPRITE_ROWS + n_rows);
+        frame.write((0, 0), &".".repeat(40), Style::Dim);
+        for column in self.x.saturating_sub(1)..=self.x.saturating_add(1) {
+            if let Ok(column) = usize::try_from(column) {
+                frame.write((0, column), "#", Style::Bright);
+            }
+        }
+        for (cycle, pixel) in self.drawing.iter().enumerate() {
+            let style = if cycle + 1 == self.drawing.len() {
+                Style::Highlight
+            } else if pixel == "#" {
+                Style::Bright
+            } else {
+                Style::Dim
+            };
+            frame.write((SPRITE_ROWS + cycle / 40, cycle % 40), pixel, style);
+        }
+        let last = self.cycle.saturating_sub(1);
+        frame.focus = Some((SPRITE_ROWS + last / 40, last % 40));
+        frame.caption = format!(
+            "Cycle {} of {}: X = {}, sum of signal strengths {}",
+            self.cycle,
+            self.commands.len(),
+            self.x,
+            self.signal_strength
+        );
+        frame
+    }
+
+    /// Like [`stream_crt`], for a single cycle
+    fn step(&mut self) -> Result<bool> {
+        let Some(command) = self.commands.get(self.cycle) else {
+            return Ok(false);
+        };
+        self.drawing.push(get_pixel(self.cycle, self.x)?);
+        self.x = add_to_x(self.x, *command)?;
+        if (self.cycle + 2) % 40 == 20 {
+            self.signal_strength =
+                add_signal_strength(self.signal_strength, self.cycle + 2, self.x)?;
+        }
+        self.cycle += 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::run_part;
+    use crate::{file_to_vec, lines};
+
+    #[test]
+    fn test_extract_commands_for_x() {
+        let input = vec![
+            "addx 10".to_string(),
+            "addx -2".to_string(),
+            "noop".to_string(),
+        ];
+        let result = extract_commands_for_x(&input).unwrap();
+        let expected = vec![0, 10, 0, -2, 0];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_extract_bad_commands() {
+        let input = vec!["addx ten".to_string()];
+        let err = extract_commands_for_x(&input).unwrap_err();
+        assert_eq!(err.column, Some(6));
+
+        let input = vec!["noop".to_string(), "jump 4".to_string()];
+        let err = extract_commands_for_x(&input).unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let input = vec!["addx 2000000000".to_string(), "addx 2000000000".to_string()];
+        assert!(part_1(&input).is_err());
+        assert!(part_2(&input).is_err());
+        assert!(Day10
+            .stream(Part::Two, &mut input.into_iter().map(Ok))
+            .unwrap()
+            .is_err());
+
+        let mut input = vec!["addx 100000000".to_string()];
+        input.extend(vec!["noop".to_string(); 60]);
+        assert!(part_1(&input).is_err());
+        assert!(Day10
+            .stream(Part::One, &mut input.clone().into_iter().map(Ok))
+            .unwrap()
+            .is_err());
+        let mut animation = CrtAnimation::new(&input).unwrap();
+        assert!((0..input.len() * 2).any(|_| animation.step().is_err()));
+
+        let input = vec!["addx 2147483646".to_string()];
+        assert_eq!(part_2(&input).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn stream_sample_input() {
+        let filename = "input/day10_sample.txt";
+        for part in Part::BOTH {
+            let mut sample = lines(filename).unwrap();
+            let streamed = Day10.stream(part, &mut sample).unwrap().unwrap();
+            let input = file_to_vec(filename).unwrap();
+            assert_eq!(streamed, run_part(&Day10, part, &input).unwrap());
+        }
+    }
+
+    #[test]
+    fn animate_sample() {
+        let input = file_to_vec("input/day10_sample.txt").unwrap();
+        let mut animation = CrtAnimation::new(&input).unwrap();
+        while animation.step().unwrap() {}
+        assert_eq!(animation.signal_strength, 13140);
+        assert_eq!(join_rows(&animation.drawing), part_2(&input).unwrap());
+        assert_eq!(animation.frame().cells.height(), 8);
+    }
+}
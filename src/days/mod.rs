@@ -0,0 +1,55 @@
+use crate::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+/// Every solved day, in order of year and day
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+/// Look up the solution for `day` of `year`, if it has been solved
+pub fn get_solution(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.year() == year && solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solutions_are_in_order() {
+        let days: Vec<(u16, u8)> = SOLUTIONS
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+
+    #[test]
+    fn get_solution_by_day() {
+        assert_eq!(get_solution(2022, 9).unwrap().title(), "Rope Bridge");
+        assert!(get_solution(2022, 25).is_none());
+        assert!(get_solution(2021, 9).is_none());
+    }
+}
@@ -0,0 +1,245 @@
+//! Saving the frames of a simulation as images, for slides rather than the terminal
+use crate::image::{Image, Rgb};
+use crate::visualize::{Frame, Style, Visualize};
+use anyhow::{bail, Context, Result};
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The colour of each [`Style`], from [`Style::Empty`] to [`Style::Highlight`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette(pub [Rgb; 5]);
+
+impl Palette {
+    /// The colours of the Advent of Code website
+    pub const DARK: Palette = Palette([
+        [15, 15, 35],
+        [51, 51, 64],
+        [204, 204, 204],
+        [0, 153, 0],
+        [255, 255, 102],
+    ]);
+
+    /// For printing, or slides with a white background
+    pub const LIGHT: Palette = Palette([
+        [255, 255, 255],
+        [221, 221, 221],
+        [85, 85, 85],
+        [0, 136, 0],
+        [255, 136, 0],
+    ]);
+
+    pub fn color(&self, style: Style) -> Rgb {
+        self.0[style as usize]
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::DARK
+    }
+}
+
+/// `dark`, `light`, or five colours like `0f0f23,333340,cccccc,009900,ffff66`
+impl FromStr for Palette {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "dark" => return Ok(Palette::DARK),
+            "light" => return Ok(Palette::LIGHT),
+            _ => {}
+        }
+        let colors: Vec<Rgb> = s.split(',').map(parse_color).collect::<Result<_>>()?;
+        match colors.try_into() {
+            Ok(colors) => Ok(Palette(colors)),
+            Err(_) => bail!(
+                "'{}' is not a valid palette, expected dark, light, or five colours like 0f0f23",
+                s
+            ),
+        }
+    }
+}
+
+fn parse_color(hex: &str) -> Result<Rgb> {
+    let digits = hex.trim().trim_start_matches('#');
+    let value = match digits.len() {
+        6 if digits.chars().all(|c| c.is_ascii_hexdigit()) => u32::from_str_radix(digits, 16).ok(),
+        _ => None,
+    };
+    match value {
+        Some(value) => {
+            let [_, r, g, b] = value.to_be_bytes();
+            Ok([r, g, b])
+        }
+        None => bail!("'{}' is not a valid colour, expected six hex digits", hex),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    /// Much bigger, but most tools can read it
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "png" => Ok(ImageFormat::Png),
+            "ppm" => Ok(ImageFormat::Ppm),
+            _ => bail!("'{}' is not a valid image format, expected png or ppm", s),
+        }
+    }
+}
+
+/// Where and how to save the frames
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportConfig {
+    pub dir: PathBuf,
+    pub format: ImageFormat,
+    /// The width and height of a cell in pixels
+    pub scale: usize,
+    pub palette: Palette,
+    /// Only save the frame after the last step
+    pub only_last: bool,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        ExportConfig {
+            dir: PathBuf::from("frames"),
+            format: ImageFormat::default(),
+            scale: 4,
+            palette: Palette::default(),
+            only_last: false,
+        }
+    }
+}
+
+/// Draw every cell of `frame` as a square of `scale` pixels in the colour of its style. Spaces are
+/// left as background, and the caption is left out.
+pub fn frame_to_image(frame: &Frame, scale: usize, palette: &Palette) -> Image {
+    let cells = &frame.cells;
+    let background = palette.color(Style::Empty);
+    let mut image = Image::new(cells.width() * scale, cells.height() * scale, background);
+    for ((row, column), cell) in cells.iter() {
+        if !cell.symbol.is_whitespace() {
+            let color = palette.color(cell.style);
+            image.fill(column * scale, row * scale, scale, scale, color);
+        }
+    }
+    image
+}
+
+fn save_image(image: &Image, format: ImageFormat, path: &Path) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("Could not create {}", path.display()))?;
+    let mut out = BufWriter::new(file);
+    match format {
+        ImageFormat::Png => image.write_png(&mut out),
+        ImageFormat::Ppm => image.write_ppm(&mut out),
+    }
+    .and_then(|_| out.flush())
+    .with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// Save every frame of `simulation` as `<name>-00000.png`, `<name>-00001.png` and so on in the
+/// directory of `config`, or only the last one as `<name>.png`. Returns the files written.
+pub fn export_frames(
+    simulation: &mut dyn Visualize,
+    name: &str,
+    config: &ExportConfig,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(&config.dir)
+        .with_context(|| format!("Could not create {}", config.dir.display()))?;
+    let extension = config.format.extension();
+    let mut paths = Vec::new();
+    if config.only_last {
+        while simulation.step()? {}
+        let path = config.dir.join(format!("{}.{}", name, extension));
+        let image = frame_to_image(&simulation.frame(), config.scale, &config.palette);
+        save_image(&image, config.format, &path)?;
+        paths.push(path);
+        return Ok(paths);
+    }
+    loop {
+        let path = config
+            .dir
+            .join(format!("{}-{:05}.{}", name, paths.len(), extension));
+        let image = frame_to_image(&simulation.frame(), config.scale, &config.palette);
+        save_image(&image, config.format, &path)?;
+        paths.push(path);
+        if !simulation.step()? {
+            return Ok(paths);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::get_solution;
+    use crate::file_to_vec;
+    use crate::runner::Part;
+
+    #[test]
+    fn parse_palette() {
+        assert_eq!("light".parse::<Palette>().unwrap(), Palette::LIGHT);
+        let palette: Palette = "#000000,111111,222222,333333,ff8000".parse().unwrap();
+        assert_eq!(palette.color(Style::Highlight), [255, 128, 0]);
+        assert!("000000,111111".parse::<Palette>().is_err());
+        assert!("000000,111111,222222,333333,ff80zz"
+            .parse::<Palette>()
+            .is_err());
+    }
+
+    #[test]
+    fn draw_frame() {
+        let mut frame = Frame::new(3, 2);
+        frame.write((1, 0), "# #", Style::Highlight);
+        let image = frame_to_image(&frame, 2, &Palette::DARK);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(1, 3), Palette::DARK.color(Style::Highlight));
+        assert_eq!(image.get(2, 3), Palette::DARK.color(Style::Empty));
+        assert_eq!(image.get(1, 1), Palette::DARK.color(Style::Empty));
+    }
+
+    #[test]
+    fn export_sample_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+        let input = file_to_vec("input/day05_sample.txt").unwrap();
+        let solution = get_solution(2022, 5).unwrap();
+        let mut config = ExportConfig {
+            dir: dir.clone(),
+            format: ImageFormat::Ppm,
+            scale: 1,
+            ..ExportConfig::default()
+        };
+        let mut simulation = solution.visualize(Part::One, &input).unwrap().unwrap();
+        let paths = export_frames(simulation.as_mut(), "day05", &config).unwrap();
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths[4], dir.join("day05-00004.ppm"));
+        let ppm = fs::read(&paths[4]).unwrap();
+        assert!(ppm.starts_with(b"P6\n11 5\n255\n"));
+
+        config.only_last = true;
+        config.format = ImageFormat::Png;
+        let mut simulation = solution.visualize(Part::One, &input).unwrap().unwrap();
+        let paths = export_frames(simulation.as_mut(), "day05", &config).unwrap();
+        assert_eq!(paths, vec![dir.join("day05.png")]);
+        assert!(paths[0].exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
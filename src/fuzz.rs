@@ -0,0 +1,411 @@
+//! Feeding mangled inputs to a day to find panics. Malformed input should give an error, so any
+//! panic is a bug.
+use crate::generate::{generator, Rng};
+use crate::runner::{run_part, stream_reader, Part};
+use crate::samples::{cases_path, SampleCases};
+use crate::verify::catch_panic;
+use crate::{read_lines, ReadOptions, Solution};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where inputs that make a day panic are saved, so they can be run again with `--input`
+pub const FINDINGS_DIR: &str = "fuzz/findings";
+
+/// Inputs are kept below this many bytes, so a run doesn't get slower and slower
+const MAX_LEN: usize = 4096;
+
+/// Other numbers are cut to this many digits, so random digits don't make inputs that are slow
+/// rather than wrong. Big numbers come from [`INTERESTING_NUMBERS`] instead.
+const MAX_DIGITS: usize = 4;
+
+/// Bytes that mean something in at least one puzzle
+const INTERESTING_BYTES: &[u8] = b"0123456789 \n-,[]$/.ABCXYZRLUDmovefrtdlsaxnop";
+
+/// Numbers that are often handled badly: the edges of the integer types, where adding or
+/// multiplying soon overflows, and one that doesn't fit in any of them
+const INTERESTING_NUMBERS: &[&str] = &[
+    "0",
+    "-1",
+    "1",
+    "10",
+    "-100",
+    "2147483647",
+    "2147483648",
+    "-2147483649",
+    "4294967296",
+    "9223372036854775807",
+    "18446744073709551615",
+    "99999999999999999999",
+];
+
+/// What happened when a day was given an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// At least one part gave an answer
+    Solved,
+    /// Every part returned an error
+    Rejected,
+    Panicked {
+        part: Part,
+        /// Whether it panicked while reading the input one line at a time
+        streamed: bool,
+        message: String,
+    },
+}
+
+/// Run both parts of `solution` on `input`, in memory and streamed if the day supports that
+pub fn check_input(solution: &dyn Solution, input: &[u8]) -> Outcome {
+    let Ok(lines) = read_lines(input, ReadOptions::default()) else {
+        return Outcome::Rejected;
+    };
+    let mut solved = false;
+    for part in Part::BOTH {
+        match catch_panic(|| run_part(solution, part, &lines)) {
+            Ok(answer) => solved |= answer.is_ok(),
+            Err(message) => {
+                return Outcome::Panicked {
+                    part,
+                    streamed: false,
+                    message,
+                }
+            }
+        }
+        let streamed = catch_panic(|| {
+            stream_reader(solution, part, &mut &input[..]).map(|answer| answer.is_ok())
+        });
+        if let Err(message) = streamed {
+            return Outcome::Panicked {
+                part,
+                streamed: true,
+                message,
+            };
+        }
+    }
+    if solved {
+        Outcome::Solved
+    } else {
+        Outcome::Rejected
+    }
+}
+
+/// The inputs to start mangling from: the sample cases of the day, and some generated inputs
+pub fn corpus(solution: &dyn Solution) -> Vec<Vec<u8>> {
+    let mut corpus = Vec::new();
+    let cases = SampleCases::load(Path::new(&cases_path(solution.year(), solution.day())));
+    for case in cases.map(|cases| cases.0).unwrap_or_default() {
+        if let Ok(lines) = case.read_input() {
+            corpus.push(to_bytes(&lines));
+        }
+    }
+    if let Some(generator) = generator(solution.year(), solution.day()) {
+        for seed in 0..4 {
+            corpus.push(to_bytes(&generator(10, &mut Rng::new(seed))));
+        }
+    }
+    if corpus.is_empty() {
+        corpus.push(Vec::new());
+    }
+    corpus
+}
+
+fn to_bytes(lines: &[String]) -> Vec<u8> {
+    let mut bytes = lines.join("\n").into_bytes();
+    bytes.push(b'\n');
+    bytes
+}
+
+/// Change `input` in one to four random ways
+pub fn mutate(input: &[u8], rng: &mut Rng) -> Vec<u8> {
+    let mut bytes = input.to_vec();
+    for _ in 0..rng.between(1, 4) {
+        let pos = rng.below(bytes.len() + 1);
+        match rng.below(8) {
+            // Replace a byte, sometimes with one that isn't valid UTF-8
+            0 if pos < bytes.len() => bytes[pos] = rng.next_u64() as u8,
+            1 if pos < bytes.len() => bytes[pos] = rng.choose(INTERESTING_BYTES),
+            2 => bytes.insert(pos, rng.choose(INTERESTING_BYTES)),
+            // Remove a few bytes
+            3 if pos < bytes.len() => {
+                let end = (pos + rng.between(1, 8) as usize).min(bytes.len());
+                bytes.drain(pos..end);
+            }
+            // Repeat a few bytes
+            4 if pos < bytes.len() => {
+                let end = (pos + rng.between(1, 16) as usize).min(bytes.len());
+                let copy = bytes[pos..end].to_vec();
+                bytes.splice(pos..pos, copy);
+            }
+            // Replace a run of digits with another number
+            5 => {
+                let start = (pos..bytes.len()).find(|&idx| bytes[idx].is_ascii_digit());
+                if let Some(start) = start {
+                    let end = (start..bytes.len())
+                        .find(|&idx| !bytes[idx].is_ascii_digit())
+                        .unwrap_or(bytes.len());
+                    let number = rng.choose(INTERESTING_NUMBERS).bytes();
+                    bytes.splice(start..end, number);
+                }
+            }
+            // Remove or repeat a whole line
+            6 | 7 => {
+                let start = bytes[..pos]
+                    .iter()
+                    .rposition(|&b| b == b'\n')
+                    .map_or(0, |idx| idx + 1);
+                let end = bytes[pos..]
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(bytes.len(), |idx| pos + idx + 1);
+                if rng.below(2) == 0 {
+                    bytes.drain(start..end);
+                } else {
+                    let line = bytes[start..end].to_vec();
+                    bytes.splice(start..start, line);
+                }
+            }
+            _ => bytes.truncate(pos),
+        }
+    }
+    bytes.truncate(MAX_LEN);
+    clip_numbers(&bytes)
+}
+
+/// Cut every run of more than [`MAX_DIGITS`] digits short, unless it is one of
+/// [`INTERESTING_NUMBERS`]
+fn clip_numbers(bytes: &[u8]) -> Vec<u8> {
+    let mut clipped = Vec::with_capacity(bytes.len());
+    for chunk in bytes.chunk_by(|a, b| a.is_ascii_digit() == b.is_ascii_digit()) {
+        let interesting = INTERESTING_NUMBERS
+            .iter()
+            .any(|number| number.trim_start_matches('-').as_bytes() == chunk);
+        if chunk[0].is_ascii_digit() && !interesting {
+            clipped.extend(&chunk[..chunk.len().min(MAX_DIGITS)]);
+        } else {
+            clipped.extend(chunk);
+        }
+    }
+    clipped
+}
+
+/// An input that makes a day panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub part: Part,
+    pub streamed: bool,
+    pub message: String,
+    pub input: Vec<u8>,
+}
+
+/// The numbers in a panic message are left out when deciding whether two panics are the same,
+/// so every index that is out of bounds doesn't count as a new finding
+fn same_panic(a: &str, b: &str) -> bool {
+    let strip = |message: &str| message.replace(|c: char| c.is_ascii_digit(), "");
+    strip(a) == strip(b)
+}
+
+/// Remove lines from the input of `finding` for as long as it still panics the same way
+pub fn minimize(solution: &dyn Solution, finding: &Finding) -> Finding {
+    let mut lines: Vec<&[u8]> = finding.input.split_inclusive(|&b| b == b'\n').collect();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let mut fewer = lines.clone();
+        fewer.remove(idx);
+        match check_input(solution, &fewer.concat()) {
+            Outcome::Panicked { message, .. } if same_panic(&message, &finding.message) => {
+                lines = fewer;
+            }
+            _ => idx += 1,
+        }
+    }
+    let input = lines.concat();
+    match check_input(solution, &input) {
+        Outcome::Panicked {
+            part,
+            streamed,
+            message,
+        } => Finding {
+            part,
+            streamed,
+            message,
+            input,
+        },
+        _ => finding.clone(),
+    }
+}
+
+/// What a fuzzing run did
+#[derive(Debug, Default)]
+pub struct FuzzStats {
+    pub runs: usize,
+    pub solved: usize,
+    pub rejected: usize,
+    /// One minimized input for each different panic
+    pub findings: Vec<Finding>,
+}
+
+/// Run `solution` on `runs` mangled inputs. Panics are caught and collected as findings, but
+/// their messages are still printed by the panic hook, which the `aoc` binary silences.
+pub fn fuzz(solution: &dyn Solution, runs: usize, seed: u64) -> FuzzStats {
+    let mut rng = Rng::new(seed);
+    let mut corpus = corpus(solution);
+    let mut stats = FuzzStats::default();
+    for _ in 0..runs {
+        let parent = rng.below(corpus.len());
+        let input = mutate(&corpus[parent], &mut rng);
+        stats.runs += 1;
+        match check_input(solution, &input) {
+            Outcome::Solved => {
+                stats.solved += 1;
+                // Inputs that still solve are close to valid, so they make good parents
+                if corpus.len() < 256 {
+                    corpus.push(input);
+                }
+            }
+            Outcome::Rejected => stats.rejected += 1,
+            Outcome::Panicked {
+                part,
+                streamed,
+                message,
+            } => {
+                let known = stats
+                    .findings
+                    .iter()
+                    .any(|finding| same_panic(&finding.message, &message));
+                if !known {
+                    let finding = Finding {
+                        part,
+                        streamed,
+                        message,
+                        input,
+                    };
+                    stats.findings.push(minimize(solution, &finding));
+                }
+            }
+        }
+    }
+    stats
+}
+
+/// Save the input of each finding in `dir`, named after the day and seed, and return the paths
+pub fn save_findings(
+    dir: &Path,
+    solution: &dyn Solution,
+    seed: u64,
+    findings: &[Finding],
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    let mut paths = Vec::new();
+    for (idx, finding) in findings.iter().enumerate() {
+        let path = dir.join(format!(
+            "{}-day{:02}-{}-{}.txt",
+            solution.year(),
+            solution.day(),
+            seed,
+            idx + 1
+        ));
+        fs::write(&path, &finding.input)
+            .with_context(|| format!("Could not write {}", path.display()))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::SOLUTIONS;
+    use crate::Answer;
+
+    /// Panics when a line says "boom", like a parser with an `unwrap` in it
+    struct Fragile;
+
+    impl Solution for Fragile {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn title(&self) -> &'static str {
+            "Fragile"
+        }
+
+        fn part_1(&self, input: &[String]) -> Result<Answer> {
+            if input.iter().any(|line| line == "boom") {
+                panic!("found a boom on line {}", input.len());
+            }
+            Ok(Answer::Int(input.len() as i64))
+        }
+
+        fn part_2(&self, input: &[String]) -> Result<Answer> {
+            anyhow::ensure!(!input.is_empty(), "the input is empty");
+            Ok(Answer::Int(0))
+        }
+    }
+
+    #[test]
+    fn errors_are_not_findings() {
+        assert_eq!(check_input(&Fragile, b"1\n2\n"), Outcome::Solved);
+        assert_eq!(check_input(&Fragile, b"\xff\n"), Outcome::Rejected);
+        let outcome = check_input(&Fragile, b"1\nboom\n2\n");
+        assert_eq!(
+            outcome,
+            Outcome::Panicked {
+                part: Part::One,
+                streamed: false,
+                message: "found a boom on line 3".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn minimize_keeps_the_panic() {
+        let finding = Finding {
+            part: Part::One,
+            streamed: false,
+            message: "found a boom on line 3".to_owned(),
+            input: b"1\nboom\n2\n".to_vec(),
+        };
+        let minimized = minimize(&Fragile, &finding);
+        assert_eq!(minimized.input, b"boom\n");
+        assert_eq!(minimized.message, "found a boom on line 1");
+    }
+
+    #[test]
+    fn mutate_is_repeatable() {
+        let input = b"move 1 from 2 to 1\nmove 3 from 1 to 3\n";
+        let mutated = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20).map(|_| mutate(input, &mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(mutated(3), mutated(3));
+        assert!(mutated(3).iter().any(|bytes| bytes != input));
+        assert!(mutated(3).iter().all(|bytes| bytes.len() <= MAX_LEN));
+    }
+
+    #[test]
+    fn clip_long_numbers() {
+        let clipped = clip_numbers(b"R 1999999999\nL 18446744073709551615\nU 2147483647\n");
+        assert_eq!(clipped, b"R 1999\nL 18446744073709551615\nU 2147483647\n");
+    }
+
+    #[test]
+    fn days_do_not_panic_on_mangled_input() {
+        for solution in SOLUTIONS {
+            // A day that was just created has no generator or answered case yet, so it is unsolved
+            let cases = SampleCases::load(Path::new(&cases_path(solution.year(), solution.day())));
+            let has_answers =
+                cases.is_ok_and(|cases| cases.0.iter().any(|c| !c.answers.is_empty()));
+            if generator(solution.year(), solution.day()).is_none() && !has_answers {
+                continue;
+            }
+            let stats = fuzz(*solution, 300, 1);
+            assert_eq!(stats.runs, 300);
+            assert!(
+                stats.findings.is_empty(),
+                "day {}: {:?}",
+                solution.day(),
+                stats.findings
+            );
+        }
+    }
+}
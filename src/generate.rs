@@ -0,0 +1,471 @@
+//! Random inputs that follow the rules of each puzzle, for stress tests and property tests
+use crate::DEFAULT_YEAR;
+
+/// Makes an input of about `size` lines for one day, see [`generator`]
+pub type Generator = fn(usize, &mut Rng) -> Vec<String>;
+
+/// A small pseudo-random number generator (SplitMix64). The same seed always gives the same
+/// numbers, so a failing input can be made again from its seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `n`, which must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `low` to `high`, both included
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    pub fn choose<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    /// Put `items` in a random order
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// The generator for `day` of `year`, if there is one
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    if year != DEFAULT_YEAR {
+        return None;
+    }
+    let generator: Generator = match day {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        9 => day09,
+        10 => day10,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+/// Groups of calories separated by blank lines
+fn day01(size: usize, rng: &mut Rng) -> Vec<String> {
+    let mut lines = Vec::new();
+    while lines.len() < size.max(1) {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        for _ in 0..rng.between(1, 15) {
+            lines.push(rng.between(1000, 60000).to_string());
+        }
+    }
+    lines
+}
+
+/// Rounds of rock paper scissors
+fn day02(size: usize, rng: &mut Rng) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+/// Groups of three rucksacks. The halves of each rucksack have exactly one item in common, and
+/// the three rucksacks of a group have exactly one badge in common.
+fn day03(size: usize, rng: &mut Rng) -> Vec<String> {
+    let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = Vec::new();
+    while lines.len() < size.max(1) {
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        // Each rucksack gets its own 17 letters, so only the badge is in all three
+        for own in letters[1..].chunks(17) {
+            let mut pool: Vec<char> = own.to_vec();
+            pool.push(badge);
+            rng.shuffle(&mut pool);
+            let shared = pool[0];
+            // The badge goes in the left half, so the right half must not have it
+            let (mut left_pool, mut right_pool) = (Vec::new(), Vec::new());
+            for (idx, &item) in pool[1..].iter().enumerate() {
+                if item == badge || idx % 2 == 0 {
+                    left_pool.push(item);
+                } else {
+                    right_pool.push(item);
+                }
+            }
+            let len = rng.between(2, 16) as usize;
+            let mut left = vec![shared];
+            if shared != badge {
+                left.push(badge);
+            }
+            while left.len() < len {
+                left.push(rng.choose(&left_pool));
+            }
+            let mut right = vec![shared];
+            while right.len() < len {
+                right.push(rng.choose(&right_pool));
+            }
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            lines.push(left.into_iter().chain(right).collect());
+        }
+    }
+    lines
+}
+
+/// Pairs of section ranges
+fn day04(size: usize, rng: &mut Rng) -> Vec<String> {
+    let range = |rng: &mut Rng| {
+        let start = rng.between(1, 99);
+        format!("{}-{}", start, rng.between(start, 99))
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}", range(rng), range(rng)))
+        .collect()
+}
+
+/// A drawing of nine stacks of crates, and moves that never take more crates than a stack has and
+/// leave every stack with a crate on top
+fn day05(size: usize, rng: &mut Rng) -> Vec<String> {
+    let n_stacks = 9;
+    let heights: Vec<usize> = (0..n_stacks).map(|_| rng.between(1, 8) as usize).collect();
+    let max_height = *heights.iter().max().unwrap_or(&0);
+    let mut lines = Vec::new();
+    for row in (0..max_height).rev() {
+        let crates: Vec<String> = heights
+            .iter()
+            .map(|&height| {
+                if height > row {
+                    format!("[{}]", rng.choose(LOWERCASE).to_ascii_uppercase() as char)
+                } else {
+                    "   ".to_owned()
+                }
+            })
+            .collect();
+        lines.push(crates.join(" "));
+    }
+    let numbers: Vec<String> = (1..=n_stacks).map(|n| format!(" {} ", n)).collect();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+
+    let mut heights = heights;
+    let mut push_move = |heights: &mut Vec<usize>, count: usize, from: usize, to: usize| {
+        heights[from] -= count;
+        heights[to] += count;
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+    };
+    for _ in 0..size.max(1) {
+        let from = loop {
+            let from = rng.below(n_stacks);
+            if heights[from] > 0 {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.below(n_stacks - 1)) % n_stacks;
+        let count = rng.between(1, heights[from].min(10) as i64) as usize;
+        push_move(&mut heights, count, from, to);
+    }
+    // Every stack started with a crate, so while one is empty another has two to give away
+    while let Some(empty) = heights.iter().position(|&height| height == 0) {
+        let from = (0..n_stacks).find(|&idx| heights[idx] > 1).unwrap_or(0);
+        push_move(&mut heights, 1, from, empty);
+    }
+    lines
+}
+
+/// A datastream of `size` letters. It ends with 14 different letters, so both markers are found.
+fn day06(size: usize, rng: &mut Rng) -> Vec<String> {
+    // Fewer letters make the markers come later
+    let letters = &LOWERCASE[..rng.between(4, 26) as usize];
+    let mut stream: Vec<u8> = (0..size.saturating_sub(14))
+        .map(|_| rng.choose(letters))
+        .collect();
+    let mut end = LOWERCASE.to_vec();
+    rng.shuffle(&mut end);
+    stream.extend(&end[..14]);
+    vec![String::from_utf8(stream).expect("the letters are ASCII")]
+}
+
+/// A terminal session that lists every directory once, going down into each with `$ cd` and back
+/// up with `$ cd ..`. Together the files take up between 40 and 50 of the 70 million on the disk,
+/// so something has to be deleted for part 2.
+fn day07(size: usize, rng: &mut Rng) -> Vec<String> {
+    let n_dirs = size / 10 + 1;
+    let n_files = size / 2 + 1;
+    // The parent of each directory but the root has a lower index
+    let parents: Vec<usize> = (1..n_dirs).map(|idx| rng.below(idx)).collect();
+    let mut files = vec![Vec::new(); n_dirs];
+    let max_file = (40_000_000 / n_files as i64).clamp(1, 300_000);
+    for idx in 0..n_files {
+        files[rng.below(n_dirs)].push((name(idx, rng), rng.between(1, max_file)));
+    }
+    let used: i64 = files.iter().flatten().map(|(_, size)| size).sum();
+    let padding = 40_000_001 - used + rng.between(0, 9_999_999);
+    files[0].push(("padding.bin".to_owned(), padding));
+
+    let mut lines = vec!["$ cd /".to_owned()];
+    // Directories to visit, where `None` means going back up
+    let mut stack = vec![Some(0)];
+    while let Some(dir) = stack.pop() {
+        let Some(dir) = dir else {
+            lines.push("$ cd ..".to_owned());
+            continue;
+        };
+        if dir != 0 {
+            lines.push(format!("$ cd d{}", dir));
+        }
+        lines.push("$ ls".to_owned());
+        let children: Vec<usize> = (1..n_dirs).filter(|&idx| parents[idx - 1] == dir).collect();
+        for &child in &children {
+            lines.push(format!("dir d{}", child));
+        }
+        for (name, size) in &files[dir] {
+            lines.push(format!("{} {}", size, name));
+        }
+        for &child in children.iter().rev() {
+            stack.push(None);
+            stack.push(Some(child));
+        }
+    }
+    lines
+}
+
+/// A file name that is different for every `idx`
+fn name(idx: usize, rng: &mut Rng) -> String {
+    let extension = rng.choose(&["", ".txt", ".dat", ".log"]);
+    let mut name = String::new();
+    let mut rest = idx;
+    loop {
+        name.push(LOWERCASE[rest % 26] as char);
+        rest /= 26;
+        if rest == 0 {
+            break;
+        }
+    }
+    name + extension
+}
+
+/// A square grid of tree heights
+fn day08(size: usize, rng: &mut Rng) -> Vec<String> {
+    let side = size.max(1);
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect()
+        })
+        .collect()
+}
+
+/// Moves of the head of the rope
+fn day09(size: usize, rng: &mut Rng) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                rng.between(1, 19)
+            )
+        })
+        .collect()
+}
+
+/// A program that runs for 240 cycles, or more for a `size` above that, always a whole number of
+/// rows of the screen. X stays where the sprite can be seen.
+fn day10(size: usize, rng: &mut Rng) -> Vec<String> {
+    let cycles = size.max(240).div_ceil(40) * 40;
+    let mut lines = Vec::new();
+    let mut x = 1;
+    let mut cycle = 0;
+    while cycle < cycles {
+        if cycle + 1 == cycles || rng.below(3) == 0 {
+            lines.push("noop".to_owned());
+            cycle += 1;
+        } else {
+            let new_x = loop {
+                let new_x = rng.between(-1, 40);
+                if new_x != x {
+                    break new_x;
+                }
+            };
+            lines.push(format!("addx {}", new_x - x));
+            x = new_x;
+            cycle += 2;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{get_solution, SOLUTIONS};
+    use crate::runner::{run_part, stream_reader, Part};
+    use crate::Answer;
+
+    const SEEDS: u64 = 20;
+
+    /// Solve `part` of `day` on a generated input
+    fn solve(day: u8, part: Part, input: &[String]) -> i64 {
+        let solution = get_solution(DEFAULT_YEAR, day).unwrap();
+        match run_part(solution, part, input) {
+            Ok(Answer::Int(answer)) => answer,
+            other => panic!("day {} part {} gave {:?} for {:?}", day, part, other, input),
+        }
+    }
+
+    fn generate(day: u8, size: usize, seed: u64) -> Vec<String> {
+        generator(DEFAULT_YEAR, day).unwrap()(size, &mut Rng::new(seed))
+    }
+
+    #[test]
+    fn rng_is_repeatable() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.between(1, 6)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (1..=6).contains(n)));
+    }
+
+    #[test]
+    fn every_day_solves_generated_inputs() {
+        for solution in SOLUTIONS {
+            // A day that was just created has no generator yet
+            let Some(generator) = generator(solution.year(), solution.day()) else {
+                continue;
+            };
+            for seed in 0..SEEDS {
+                for size in [1, 10, 100] {
+                    let input = generator(size, &mut Rng::new(seed));
+                    for part in Part::BOTH {
+                        let answer = run_part(*solution, part, &input);
+                        assert!(
+                            answer.is_ok(),
+                            "day {} part {} failed with seed {} and size {}: {:?}",
+                            solution.day(),
+                            part,
+                            seed,
+                            size,
+                            answer
+                        );
+                        // Streaming must give the same answer on days that can do it
+                        let text = input.join("\n") + "\n";
+                        if let Some(streamed) = stream_reader(*solution, part, &mut text.as_bytes())
+                        {
+                            assert_eq!(streamed.unwrap(), answer.unwrap());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day01_top_three_are_at_least_the_top_one() {
+        for seed in 0..SEEDS {
+            let input = generate(1, 100, seed);
+            assert!(solve(1, Part::Two, &input) >= solve(1, Part::One, &input));
+        }
+    }
+
+    #[test]
+    fn day04_overlaps_are_at_least_the_containing_pairs() {
+        for seed in 0..SEEDS {
+            let input = generate(4, 100, seed);
+            assert!(solve(4, Part::Two, &input) >= solve(4, Part::One, &input));
+        }
+    }
+
+    #[test]
+    fn day05_has_a_crate_on_every_stack() {
+        for seed in 0..SEEDS {
+            let input = generate(5, 100, seed);
+            let solution = get_solution(DEFAULT_YEAR, 5).unwrap();
+            for part in Part::BOTH {
+                let answer = run_part(solution, part, &input).unwrap().to_string();
+                assert_eq!(answer.len(), 9, "{}", answer);
+            }
+        }
+    }
+
+    #[test]
+    fn day06_message_marker_comes_after_packet_marker() {
+        for seed in 0..SEEDS {
+            let input = generate(6, 500, seed);
+            assert!(solve(6, Part::Two, &input) >= solve(6, Part::One, &input) + 10);
+        }
+    }
+
+    #[test]
+    fn day07_frees_enough_space() {
+        for seed in 0..SEEDS {
+            let input = generate(7, 300, seed);
+            let used: i64 = input
+                .iter()
+                .filter_map(|line| line.split_once(' ')?.0.parse::<i64>().ok())
+                .sum();
+            let deleted = solve(7, Part::Two, &input);
+            assert!(deleted >= used - 40_000_000 && deleted <= used);
+        }
+    }
+
+    #[test]
+    fn day08_sees_at_least_the_edge() {
+        for seed in 0..SEEDS {
+            let input = generate(8, 20, seed);
+            let visible = solve(8, Part::One, &input);
+            assert!((4 * 19..=20 * 20).contains(&visible), "{}", visible);
+        }
+    }
+
+    #[test]
+    fn day09_visits_at_most_moves_plus_one() {
+        for seed in 0..SEEDS {
+            let input = generate(9, 100, seed);
+            let moves: i64 = input
+                .iter()
+                .map(|line| line[2..].parse::<i64>().unwrap())
+                .sum();
+            for part in Part::BOTH {
+                let visited = solve(9, part, &input);
+                assert!((1..=moves + 1).contains(&visited), "{}", visited);
+            }
+        }
+    }
+
+    #[test]
+    fn day10_draws_whole_rows() {
+        let input = generate(10, 300, 1);
+        let solution = get_solution(DEFAULT_YEAR, 10).unwrap();
+        let drawing = run_part(solution, Part::Two, &input).unwrap().to_string();
+        let rows: Vec<&str> = drawing.lines().collect();
+        assert_eq!(rows.len(), 8);
+        assert!(rows.iter().all(|row| row.len() == 40));
+    }
+}
@@ -0,0 +1,308 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on the plane. `y` grows upwards, so [`Direction::Up`] adds one to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of king moves between the points, where diagonal steps count as one
+    pub fn chebyshev(self, other: Point2) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The sign of each coordinate, so every coordinate is -1, 0 or 1
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// One step towards `target`, diagonally if needed, or `self` if they are the same
+    pub fn step_towards(self, target: Point2) -> Point2 {
+        self + (target - self).signum()
+    }
+
+    /// Whether `other` is next to this point, including diagonally and on top of it
+    pub fn is_touching(self, other: Point2) -> bool {
+        self.chebyshev(other) <= 1
+    }
+
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |dir| self + dir.unit())
+    }
+
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |dir| self + dir.unit())
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Point2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, factor: i32) -> Point2 {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four directions along the axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step of length one in this direction
+    pub fn unit(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, 1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, -1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// One of the eight directions a king can move in, named like compass points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// Turn 45 degrees clockwise
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turn 45 degrees counterclockwise
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step in this direction, where diagonal steps change both coordinates by one
+    pub fn unit(self) -> Point2 {
+        let (x, y) = match self {
+            Direction8::N => (0, 1),
+            Direction8::NE => (1, 1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, -1),
+            Direction8::S => (0, -1),
+            Direction8::SW => (-1, -1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, 1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+/// The smallest rectangle containing a set of points, with both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl BoundingBox {
+    /// The box around a single point
+    pub fn new(point: Point2) -> Self {
+        BoundingBox {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The box around all of `points`, or `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Point2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = BoundingBox::new(points.next()?);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grow the box so it contains `point`
+    pub fn include(&mut self, point: Point2) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, -4));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(-a, Point2::new(-1, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn step_towards_moves_diagonally() {
+        let tail = Point2::new(1, 1);
+        assert_eq!(tail.step_towards(Point2::new(3, 2)), Point2::new(2, 2));
+        assert_eq!(tail.step_towards(Point2::new(1, -5)), Point2::new(1, 0));
+        assert_eq!(tail.step_towards(tail), tail);
+        assert!(tail.is_touching(Point2::new(2, 0)));
+        assert!(!tail.is_touching(Point2::new(3, 1)));
+    }
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for dir in Direction::ALL {
+            assert_eq!(dir.unit() + dir.opposite().unit(), Point2::ORIGIN);
+            assert_eq!(Direction8::from(dir).unit(), dir.unit());
+        }
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::NE.opposite(), Direction8::SW);
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(Point2::ORIGIN.neighbours_4().count(), 4);
+        let neighbours: Vec<Point2> = Point2::ORIGIN.neighbours_8().collect();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.iter().all(|&p| p.chebyshev(Point2::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point2::new(2, -1), Point2::new(-3, 4), Point2::new(0, 0)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-3, -1));
+        assert_eq!(bounds.max, Point2::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains(Point2::new(-3, 4)));
+        assert!(!bounds.contains(Point2::new(3, 0)));
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+}
@@ -0,0 +1,379 @@
+use crate::AocError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a [`Grid`], as `(row, column)` counted from the top left
+pub type Pos = (usize, usize);
+
+/// A step between neighbouring positions, as `(rows, columns)`
+pub type Step = (isize, isize);
+
+/// Up, right, down and left
+pub const STEPS_4: [Step; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The four steps of [`STEPS_4`] and the four diagonals, clockwise from up
+pub const STEPS_8: [Step; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangle of cells, like the maps many puzzles are drawn as
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid where every cell is `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Build a grid from its rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (idx, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                let message = format!("expected {} cells, like the first row", width);
+                return Err(AocError::input(message).at_line(idx));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parse a map with one char per cell. `parse_cell` returns `None` for chars that are not
+    /// allowed, which is an error pointing at the char.
+    pub fn parse(
+        input: &[String],
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, AocError> {
+        let mut rows = Vec::new();
+        for (idx, line) in input.iter().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    parse_cell(c).ok_or_else(|| {
+                        let message = format!("unexpected character {:?}", c);
+                        AocError::new(idx, line, message).at_column(column)
+                    })
+                })
+                .collect::<Result<Vec<T>, AocError>>()?;
+            if row.is_empty() {
+                return Err(AocError::new(idx, line, "the line is empty"));
+            }
+            if let Some(first_row) = rows.first().map(Vec::len) {
+                if row.len() != first_row {
+                    let message = format!("expected {} characters, like the first line", first_row);
+                    return Err(AocError::new(idx, line, message));
+                }
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(AocError::input("the input is empty"));
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(pos.0 * self.width + pos.1)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(pos.0 * self.width + pos.1)
+        } else {
+            None
+        }
+    }
+
+    /// Set the cell at `pos`, or return `false` if it is outside the grid
+    pub fn set(&mut self, pos: Pos, value: T) -> bool {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position one `step` away from `pos`, if it is in the grid
+    pub fn step(&self, (row, column): Pos, (d_row, d_column): Step) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            column.checked_add_signed(d_column)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The neighbours of `pos` up, right, down and left that are in the grid
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        STEPS_4
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// Like [`Grid::neighbours_4`], but also with the diagonal neighbours
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        STEPS_8
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The positions from `pos` going `step` at a time until the edge, not including `pos`
+    pub fn ray(&self, pos: Pos, step: Step) -> impl Iterator<Item = Pos> + '_ {
+        let mut current = pos;
+        std::iter::from_fn(move || {
+            current = self.step(current, step)?;
+            Some(current)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for 0, but then there are no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of `column` from top to bottom. Panics if it is outside the grid, like
+    /// [`Grid::row`].
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "column {} is outside a grid {} wide",
+            column,
+            self.width
+        );
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.column(column))
+    }
+
+    /// Apply `f` to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    /// Turn a quarter to the right, so the first column becomes the first row in reverse
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (self.height - 1 - column, row)
+        })
+    }
+
+    /// Turn a quarter to the left, so the last column becomes the first row
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (column, self.width - 1 - row)
+        })
+    }
+
+    /// A new `width` by `height` grid, where each cell is copied from `source(pos)` in this grid
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for column in 0..width {
+                cells.push(self[source((row, column))].clone());
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+/// One line per row, with no separator between the cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        let input = vec!["abc".to_owned(), "def".to_owned()];
+        Grid::parse(&input, Some).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 0)), Some(&'d'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = vec!["12".to_owned(), "3x".to_owned()];
+        let err = Grid::parse(&input, |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+
+        let input = vec!["12".to_owned(), "3".to_owned()];
+        assert!(Grid::parse(&input, |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse(&[], Some).is_err());
+    }
+
+    #[test]
+    fn set_is_bounds_checked() {
+        let mut grid = Grid::new(2, 2, 0);
+        assert!(grid.set((1, 1), 5));
+        assert!(!grid.set((1, 2), 5));
+        assert_eq!(grid[(1, 1)], 5);
+    }
+
+    #[test]
+    fn neighbours_at_the_edge() {
+        let grid = sample();
+        let neighbours: Vec<Pos> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn ray_stops_at_the_edge() {
+        let grid = sample();
+        let ray: Vec<&char> = grid.ray((0, 0), (0, 1)).map(|pos| &grid[pos]).collect();
+        assert_eq!(ray, vec![&'b', &'c']);
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        let column: String = grid.column(2).collect();
+        assert_eq!(column, "cf");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside a grid 3 wide")]
+    fn column_is_bounds_checked() {
+        // Without the check this would be the rest of the second row
+        let _ = sample().column(3);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        let turned = grid
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise();
+        assert_eq!(turned, grid.rotate_counterclockwise());
+    }
+}
@@ -0,0 +1,41 @@
+//! Turning the HTML of puzzle pages and submission replies into text
+
+/// Remove everything between `<` and `>`, like the `<em>` highlights inside code blocks, and replace
+/// the character references that show up on the website
+pub fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    unescape(&text)
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_and_references() {
+        assert_eq!(to_text("<code>1 <em>&lt;</em> 2</code>"), "1 < 2");
+        assert_eq!(
+            to_text("That&#39;s &amp;quot;it&quot;"),
+            "That's &quot;it\""
+        );
+        assert_eq!(to_text("a > b"), "a > b");
+    }
+}
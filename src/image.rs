@@ -0,0 +1,347 @@
+//! Writing RGB images as PPM or PNG, without any dependencies
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+/// A picture with 8 bits per colour channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Row by row, three bytes per pixel
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// A `width` by `height` image where every pixel is `color`
+    pub fn new(width: usize, height: usize, color: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: color.repeat(width * height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        let idx = 3 * (y * self.width + x);
+        [self.pixels[idx], self.pixels[idx + 1], self.pixels[idx + 2]]
+    }
+
+    /// Paint the `width` by `height` rectangle with its top left corner at `x`, `y`, as far as it
+    /// fits in the image
+    pub fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        let columns = x.min(self.width)..(x + width).min(self.width);
+        for row in y.min(self.height)..(y + height).min(self.height) {
+            let start = 3 * (row * self.width + columns.start);
+            let end = 3 * (row * self.width + columns.end);
+            for pixel in self.pixels[start..end].chunks_mut(3) {
+                pixel.copy_from_slice(&color);
+            }
+        }
+    }
+
+    /// Write the image as a binary PPM, which is simple but big
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)
+    }
+
+    /// Write the image as a PNG
+    pub fn write_png(&self, out: &mut impl Write) -> io::Result<()> {
+        let too_big = |_| io::Error::new(io::ErrorKind::InvalidInput, "the image is too big");
+        let mut header = Vec::new();
+        header.extend(u32::try_from(self.width).map_err(too_big)?.to_be_bytes());
+        header.extend(u32::try_from(self.height).map_err(too_big)?.to_be_bytes());
+        // 8 bits per channel, RGB, and the only compression, filter and interlace methods
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Every row is stored as the difference with the row above, which makes repeated rows
+        // all zeros
+        let row_len = 3 * self.width;
+        let mut filtered = Vec::with_capacity((row_len + 1) * self.height);
+        for row in 0..self.height {
+            let pixels = &self.pixels[row * row_len..(row + 1) * row_len];
+            filtered.push(2);
+            match row.checked_sub(1) {
+                Some(above) => {
+                    let above = &self.pixels[above * row_len..row * row_len];
+                    filtered.extend(pixels.iter().zip(above).map(|(a, b)| a.wrapping_sub(*b)));
+                }
+                None => filtered.extend(pixels),
+            }
+        }
+
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+        write_chunk(out, b"IHDR", &header)?;
+        write_chunk(out, b"IDAT", &zlib(&filtered))?;
+        write_chunk(out, b"IEND", &[])
+    }
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the image is too big"))?;
+    out.write_all(&len.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(&[kind.as_slice(), data].concat());
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// The CRC-32 that PNG chunks end with
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// The checksum at the end of zlib data
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Compress `data` in the zlib format that PNG uses
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, and the fastest compression level
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+/// The smallest length of each length code from 257, and how many extra bits it has
+const LENGTHS: [(usize, u32); 29] = [
+    (3, 0),
+    (4, 0),
+    (5, 0),
+    (6, 0),
+    (7, 0),
+    (8, 0),
+    (9, 0),
+    (10, 0),
+    (11, 1),
+    (13, 1),
+    (15, 1),
+    (17, 1),
+    (19, 2),
+    (23, 2),
+    (27, 2),
+    (31, 2),
+    (35, 3),
+    (43, 3),
+    (51, 3),
+    (59, 3),
+    (67, 4),
+    (83, 4),
+    (99, 4),
+    (115, 4),
+    (131, 5),
+    (163, 5),
+    (195, 5),
+    (227, 5),
+    (258, 0),
+];
+
+/// The smallest distance of each distance code, and how many extra bits it has
+const DISTANCES: [(usize, u32); 30] = [
+    (1, 0),
+    (2, 0),
+    (3, 0),
+    (4, 0),
+    (5, 1),
+    (7, 1),
+    (9, 2),
+    (13, 2),
+    (17, 3),
+    (25, 3),
+    (33, 4),
+    (49, 4),
+    (65, 5),
+    (97, 5),
+    (129, 6),
+    (193, 6),
+    (257, 7),
+    (385, 7),
+    (513, 8),
+    (769, 8),
+    (1025, 9),
+    (1537, 9),
+    (2049, 10),
+    (3073, 10),
+    (4097, 11),
+    (6145, 11),
+    (8193, 12),
+    (12289, 12),
+    (16385, 13),
+    (24577, 13),
+];
+
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+/// Compress `data` as a single deflate block with the fixed Huffman codes. Repeats are found by
+/// looking up the last place the next three bytes were seen, which is enough for pictures made of
+/// rectangles.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    // The last block, with fixed codes
+    bits.write(1, 1);
+    bits.write(1, 2);
+    let mut last_seen = vec![usize::MAX; 1 << 15];
+    let hash = |pos: usize| {
+        let key = u32::from_le_bytes([data[pos], data[pos + 1], data[pos + 2], 0]);
+        (key.wrapping_mul(2_654_435_761) >> 17) as usize
+    };
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut length = 0;
+        let mut distance = 0;
+        if pos + MIN_MATCH <= data.len() {
+            let candidate = last_seen[hash(pos)];
+            last_seen[hash(pos)] = pos;
+            if candidate != usize::MAX && pos - candidate <= WINDOW {
+                let max_len = MAX_MATCH.min(data.len() - pos);
+                length = (0..max_len)
+                    .take_while(|&offset| data[candidate + offset] == data[pos + offset])
+                    .count();
+                distance = pos - candidate;
+            }
+        }
+        if length >= MIN_MATCH {
+            let code = LENGTHS
+                .iter()
+                .rposition(|&(base, _)| base <= length)
+                .unwrap_or(0);
+            let (base, extra) = LENGTHS[code];
+            bits.write_symbol(257 + code);
+            bits.write((length - base) as u32, extra);
+            let code = DISTANCES
+                .iter()
+                .rposition(|&(base, _)| base <= distance)
+                .unwrap_or(0);
+            let (base, extra) = DISTANCES[code];
+            bits.write_code(code as u32, 5);
+            bits.write((distance - base) as u32, extra);
+            // Remember the skipped positions too, so the next repeat can be found
+            for skipped in pos + 1..(pos + length).min(data.len().saturating_sub(MIN_MATCH - 1)) {
+                last_seen[hash(skipped)] = skipped;
+            }
+            pos += length;
+        } else {
+            bits.write_symbol(usize::from(data[pos]));
+            pos += 1;
+        }
+    }
+    bits.write_symbol(256);
+    bits.finish()
+}
+
+/// Packs bits into bytes from the least significant bit, like deflate wants
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    n_bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, n_bits: u32) {
+        self.buffer |= u64::from(value) << self.n_bits;
+        self.n_bits += n_bits;
+        while self.n_bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.n_bits -= 8;
+        }
+    }
+
+    /// Huffman codes are written from the most significant bit
+    fn write_code(&mut self, code: u32, n_bits: u32) {
+        self.write(code.reverse_bits() >> (32 - n_bits), n_bits);
+    }
+
+    /// Write a literal byte, a length, or the end of the block, with the fixed codes
+    fn write_symbol(&mut self, symbol: usize) {
+        let symbol = symbol as u32;
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.n_bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn fill_and_write_ppm() {
+        let mut image = Image::new(3, 2, [0, 0, 0]);
+        image.fill(1, 1, 5, 5, [255, 128, 0]);
+        assert_eq!(image.get(0, 1), [0, 0, 0]);
+        assert_eq!(image.get(2, 1), [255, 128, 0]);
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 3 * 6);
+    }
+
+    #[test]
+    fn png_compresses_repeats() {
+        let mut image = Image::new(400, 300, [15, 15, 35]);
+        image.fill(100, 100, 50, 50, [255, 255, 102]);
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\x01\x90\0\0\x01\x2c"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        assert!(png.len() < 4000, "{} bytes", png.len());
+    }
+
+    #[test]
+    fn deflate_short_inputs() {
+        // An empty block is the header and the end code, in ten bits
+        assert_eq!(deflate(&[]), vec![0x03, 0x00]);
+        assert_eq!(
+            zlib(b"a"),
+            vec![0x78, 0x01, 0x4b, 0x04, 0x00, 0x00, 0x62, 0x00, 0x62]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::ops::{Add, Sub};
+
+/// Integer types that [`Interval`] works with
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+
+impl<T> Integer for T where T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8> {}
+
+fn one<T: Integer>() -> T {
+    T::from(1)
+}
+
+/// A range of integers where both ends are included, like `2-4` in day 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// The interval from `start` to `end`, or `None` if `start` is after `end`
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The number of values in the interval
+    pub fn len(&self) -> T {
+        self.end - self.start + one()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether all of `other` is inside this interval
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the intervals have at least one value in common
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The interval covering both, if they overlap or are next to each other so there is no gap
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        // Written with subtraction so it can't overflow at the ends of the type
+        let touches = self.overlaps(other)
+            || (self.end < other.start && other.start - self.end == one())
+            || (other.end < self.start && self.start - other.end == one());
+        touches.then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+}
+
+/// A set of integers stored as sorted intervals, which are merged whenever they overlap or touch
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    /// Sorted, with gaps between every two intervals
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The merged intervals, in order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Add every value in `interval`
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for existing in self.intervals.drain(..) {
+            match merged.union(&existing) {
+                Some(union) => merged = union,
+                None => kept.push(existing),
+            }
+        }
+        let idx = kept.partition_point(|existing| existing.start < merged.start);
+        kept.insert(idx, merged);
+        self.intervals = kept;
+    }
+
+    /// Remove every value in `interval`, splitting the intervals it cuts through
+    pub fn remove(&mut self, interval: Interval<T>) {
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for existing in self.intervals.drain(..) {
+            if !existing.overlaps(&interval) {
+                kept.push(existing);
+                continue;
+            }
+            if existing.start < interval.start {
+                kept.push(Interval {
+                    start: existing.start,
+                    end: interval.start - one(),
+                });
+            }
+            if interval.end < existing.end {
+                kept.push(Interval {
+                    start: interval.end + one(),
+                    end: existing.end,
+                });
+            }
+        }
+        self.intervals = kept;
+    }
+
+    /// The values in this set that are not in `other`
+    pub fn subtract(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.remove(interval);
+        }
+        result
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    /// Whether every value in `interval` is in the set
+    pub fn covers(&self, interval: &Interval<T>) -> bool {
+        self.intervals
+            .iter()
+            .any(|existing| existing.contains_interval(interval))
+    }
+
+    /// How many values of `interval` are in the set
+    pub fn coverage(&self, interval: &Interval<T>) -> T {
+        self.intervals
+            .iter()
+            .filter_map(|existing| existing.intersection(interval))
+            .fold(T::from(0), |total, overlap| total + overlap.len())
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |total, interval| total + interval.len())
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn interval_operations() {
+        let a = interval(2, 6);
+        let b = interval(4, 8);
+        assert_eq!(a.len(), 5);
+        assert!(a.contains(6) && !a.contains(7));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&interval(7, 9)));
+        assert!(a.contains_interval(&interval(3, 4)));
+        assert_eq!(a.intersection(&b), Some(interval(4, 6)));
+        assert_eq!(a.intersection(&interval(7, 9)), None);
+        assert_eq!(a.union(&b), Some(interval(2, 8)));
+        assert_eq!(a.union(&interval(7, 9)), Some(interval(2, 9)));
+        assert_eq!(a.union(&interval(8, 9)), None);
+        assert_eq!(Interval::new(3, 2), None);
+    }
+
+    #[test]
+    fn set_merges_intervals() {
+        let set: IntervalSet<i32> = [
+            interval(10, 12),
+            interval(1, 3),
+            interval(4, 5),
+            interval(11, 20),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.intervals(), &[interval(1, 5), interval(10, 20)]);
+        assert_eq!(set.len(), 16);
+        assert!(set.contains(15) && !set.contains(7));
+        assert!(set.covers(&interval(2, 4)));
+        assert!(!set.covers(&interval(4, 10)));
+        assert_eq!(set.coverage(&interval(4, 10)), 3);
+    }
+
+    #[test]
+    fn set_subtraction() {
+        let mut set: IntervalSet<i32> = [interval(1, 10)].into_iter().collect();
+        set.remove(interval(4, 5));
+        assert_eq!(set.intervals(), &[interval(1, 3), interval(6, 10)]);
+
+        let other: IntervalSet<i32> = [interval(0, 1), interval(9, 12)].into_iter().collect();
+        let result = set.subtract(&other);
+        assert_eq!(result.intervals(), &[interval(2, 3), interval(6, 8)]);
+        assert!(result.subtract(&result).is_empty());
+    }
+}
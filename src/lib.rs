@@ -1,13 +1,434 @@
+use anyhow::Result;
+use std::fmt;
 use std::fs;
 use std::io;
 use std::io::{BufRead, BufReader};
 use std::num::ParseIntError;
+use std::path::Path;
+
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod days;
+pub mod export;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod html;
+pub mod image;
+pub mod interval;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod samples;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+pub mod visualize;
+pub mod watch;
+
+pub use grid::Grid;
+use runner::Part;
+use visualize::Visualize;
+
+/// The answer to one part of a puzzle, as it would be submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// The year solutions are for unless they say otherwise, see [`Solution::year`]
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Solves one part of a puzzle from already parsed input, see [`Solution::prepare`]
+pub type Solver<'a> = Box<dyn Fn() -> Result<Answer> + 'a>;
+
+/// Input lines that are read as they are needed, see [`Solution::stream`]
+pub type LineStream<'a> = dyn Iterator<Item = io::Result<String>> + 'a;
+
+/// A solution to a single day of Advent of Code.
+///
+/// Every day implements this and is listed in [`days::SOLUTIONS`], so the `aoc` binary can run it.
+pub trait Solution: Sync {
+    /// The year the puzzle is from
+    fn year(&self) -> u16 {
+        DEFAULT_YEAR
+    }
+
+    /// The day in December the puzzle was released
+    fn day(&self) -> u8;
+
+    /// The title of the puzzle, as given on the website
+    fn title(&self) -> &'static str;
+
+    fn part_1(&self, input: &[String]) -> Result<Answer>;
+
+    fn part_2(&self, input: &[String]) -> Result<Answer>;
+
+    /// Parse `input` for `part`, and return a [`Solver`] that solves it from the parsed input.
+    ///
+    /// This lets benchmarks time parsing and solving separately. Days without a separate
+    /// parsing step can leave the default, which does all the work in the solver.
+    fn prepare<'a>(&'a self, part: Part, input: &'a [String]) -> Result<Solver<'a>> {
+        Ok(Box::new(move || match part {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        }))
+    }
+
+    /// Solve `part` while reading `lines` one at a time, so memory use stays flat however big the
+    /// input is. Days that need all of the input at once return `None`, which is the default.
+    fn stream(&self, _part: Part, _lines: &mut LineStream) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// Like [`Solution::stream`], but reading `input` a byte at a time, for days whose input is a
+    /// single line that can be as long as the file. Other days return `None` without reading
+    /// anything, which is the default.
+    fn stream_bytes(&self, _part: Part, _input: &mut dyn BufRead) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// Show how `part` is solved from `input` one step at a time, for `aoc run --visualize`. Days
+    /// that are not simulations return `None`, which is the default.
+    fn visualize(&self, _part: Part, _input: &[String]) -> Option<Result<Box<dyn Visualize>>> {
+        None
+    }
+
+    /// Where the real puzzle input for this day is stored
+    fn input_path(&self) -> String {
+        input_path(self.year(), self.day())
+    }
+}
+
+/// Where the real puzzle input for `day` of `year` is stored, by default: `input/<year>/dayXX.txt`.
+/// Inputs for [`DEFAULT_YEAR`] from before there were several years are in `input/dayXX.txt`, which
+/// is used if only that file exists.
+pub fn input_path(year: u16, day: u8) -> String {
+    let path = format!("input/{}/day{:02}.txt", year, day);
+    let flat = format!("input/day{:02}.txt", day);
+    if year == DEFAULT_YEAR && !Path::new(&path).exists() && Path::new(&flat).exists() {
+        flat
+    } else {
+        path
+    }
+}
+
+/// Malformed puzzle input, with as much detail as is known about where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    /// The input file, added by the runner since solutions only see the lines
+    pub file: Option<String>,
+    /// Line number, counted from 1
+    pub line: Option<usize>,
+    /// Column, counted in chars from 1
+    pub column: Option<usize>,
+    /// The offending text, usually the whole line
+    pub text: String,
+    /// What is wrong with the text
+    pub message: String,
+}
+
+impl AocError {
+    /// An error on the line at `line_idx`, counted from 0 like `enumerate` does
+    pub fn new(line_idx: usize, text: &str, message: impl Into<String>) -> Self {
+        AocError::in_text(text, message).at_line(line_idx)
+    }
+
+    /// An error in `text`, for parsers that don't know which line they are parsing.
+    /// The caller should add the line with [`AocError::at_line`].
+    pub fn in_text(text: &str, message: impl Into<String>) -> Self {
+        AocError {
+            file: None,
+            line: None,
+            column: None,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the input as a whole, rather than a single line
+    pub fn input(message: impl Into<String>) -> Self {
+        AocError {
+            file: None,
+            line: None,
+            column: None,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Point at the line at `line_idx`, counted from 0
+    pub fn at_line(mut self, line_idx: usize) -> Self {
+        self.line = Some(line_idx + 1);
+        self
+    }
+
+    /// Point at the char at `column_idx` in the line, counted from 0
+    pub fn at_column(mut self, column_idx: usize) -> Self {
+        self.column = Some(column_idx + 1);
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Formatted like compiler errors, file:line:column, so editors can jump to it
+        let location: Vec<String> = [
+            self.file.clone(),
+            self.line.map(|line| line.to_string()),
+            self.column.map(|column| column.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " in {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// How [`read_lines`] cleans up lines, for inputs that were saved on other machines.
+///
+/// The default does all of it. [`ReadOptions::STRICT`] does none of it, and returns an error for a
+/// missing final newline instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadOptions {
+    /// Remove the `\r` of `\r\n` line endings
+    pub crlf: bool,
+    /// Remove spaces and tabs at the end of each line
+    pub trailing_whitespace: bool,
+    /// Accept a last line without a newline. Otherwise it is an error, since it can mean the
+    /// file was cut short.
+    pub missing_final_newline: bool,
+}
+
+impl ReadOptions {
+    pub const STRICT: ReadOptions = ReadOptions {
+        crlf: false,
+        trailing_whitespace: false,
+        missing_final_newline: false,
+    };
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions {
+            crlf: true,
+            trailing_whitespace: true,
+            missing_final_newline: true,
+        }
+    }
+}
 
 /// Read the file at `filename` and returns each line as a `String` in a `Vec`
 pub fn file_to_vec(filename: &str) -> io::Result<Vec<String>> {
+    file_to_vec_with(filename, ReadOptions::default())
+}
+
+/// Like [`file_to_vec`], but with explicit [`ReadOptions`]
+pub fn file_to_vec_with(filename: &str, options: ReadOptions) -> io::Result<Vec<String>> {
+    lines_with(filename, options)?.collect()
+}
+
+/// Read all lines from `reader`. Unlike [`BufRead::lines`] followed by `map_while`, this stops at
+/// the first I/O error or invalid UTF-8, and says on which line it happened.
+pub fn read_lines(reader: impl BufRead, options: ReadOptions) -> io::Result<Vec<String>> {
+    lines_from_reader(reader, options).collect()
+}
+
+/// Read the file at `filename` one line at a time, so memory use doesn't grow with the file
+pub fn lines(filename: &str) -> io::Result<Lines<BufReader<fs::File>>> {
+    lines_with(filename, ReadOptions::default())
+}
+
+/// Like [`lines`], but with explicit [`ReadOptions`]
+pub fn lines_with(filename: &str, options: ReadOptions) -> io::Result<Lines<BufReader<fs::File>>> {
     let file_in = fs::File::open(filename)?;
-    let file_reader = BufReader::new(file_in);
-    Ok(file_reader.lines().map_while(io::Result::ok).collect())
+    let mut lines = lines_from_reader(BufReader::new(file_in), options);
+    lines.file = Some(filename.to_owned());
+    Ok(lines)
+}
+
+/// Read `reader` one line at a time, like [`lines`] does for a file
+pub fn lines_from_reader<R: BufRead>(reader: R, options: ReadOptions) -> Lines<R> {
+    Lines {
+        reader,
+        options,
+        file: None,
+        line_idx: 0,
+        done: false,
+    }
+}
+
+/// Lines read one at a time, see [`lines`]. Stops after the first error.
+pub struct Lines<R> {
+    reader: R,
+    options: ReadOptions,
+    /// The file being read, for error messages
+    file: Option<String>,
+    line_idx: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut buffer = Vec::new();
+        let n_read = self
+            .reader
+            .read_until(b'\n', &mut buffer)
+            .map_err(|err| self.error(err.kind(), &format!("read error: {}", err), None))?;
+        if n_read == 0 {
+            return Ok(None);
+        }
+        if buffer.last() == Some(&b'\n') {
+            buffer.pop();
+        } else if !self.options.missing_final_newline {
+            let message = "the last line has no newline, the file may be cut short";
+            return Err(self.error(io::ErrorKind::InvalidData, message, None));
+        }
+        let mut line = String::from_utf8(buffer).map_err(|err| {
+            let valid = &err.as_bytes()[..err.utf8_error().valid_up_to()];
+            let column = String::from_utf8_lossy(valid).chars().count();
+            self.error(io::ErrorKind::InvalidData, "invalid UTF-8", Some(column))
+        })?;
+        if self.options.crlf && line.ends_with('\r') {
+            line.pop();
+        }
+        if self.options.trailing_whitespace {
+            line.truncate(line.trim_end_matches([' ', '\t']).len());
+        }
+        Ok(Some(line))
+    }
+
+    /// An error on the current line, with an [`AocError`] inside that says where it is
+    fn error(&self, kind: io::ErrorKind, message: &str, column_idx: Option<usize>) -> io::Error {
+        let mut err = AocError::input(message).at_line(self.line_idx);
+        if let Some(column_idx) = column_idx {
+            err = err.at_column(column_idx);
+        }
+        if let Some(file) = &self.file {
+            err = err.in_file(file);
+        }
+        io::Error::new(kind, err)
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_line().transpose();
+        match result {
+            Some(Ok(_)) => self.line_idx += 1,
+            _ => self.done = true,
+        }
+        result
+    }
+}
+
+/// A section of the input between blank lines, see [`groups`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group<'a> {
+    /// Index of the first line of the group in the whole input, for error messages
+    pub start: usize,
+    pub lines: &'a [String],
+}
+
+impl<'a> Group<'a> {
+    /// The lines of the group, with their index in the whole input
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, &'a String)> + 'a {
+        let start = self.start;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(offset, line)| (start + offset, line))
+    }
+}
+
+/// Split `input` into the groups of lines that are separated by blank lines. Leading, trailing
+/// and repeated blank lines never give an empty group.
+pub fn groups(input: &[String]) -> Groups<'_> {
+    Groups { input, idx: 0 }
+}
+
+/// Iterator over the groups in the input, see [`groups`]
+pub struct Groups<'a> {
+    input: &'a [String],
+    idx: usize,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Group<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_blank = |line: &String| line.trim().is_empty();
+        let rest = &self.input[self.idx..];
+        let start = self.idx + rest.iter().position(|line| !is_blank(line))?;
+        let rest = &self.input[start..];
+        let len = rest.iter().position(is_blank).unwrap_or(rest.len());
+        self.idx = start + len;
+        Some(Group {
+            start,
+            lines: &rest[..len],
+        })
+    }
 }
 
 pub fn string_to_int(input: &[String]) -> Result<Vec<i32>, ParseIntError> {
@@ -34,6 +455,62 @@ mod tests {
     fn panics_on_bad_filename() {
         let _vec = file_to_vec("input/not-a-real-file.txt").unwrap();
     }
+    #[test]
+    fn read_lines_reports_invalid_utf8() {
+        let bytes: &[u8] = b"1000\n2\xff00\n3000\n";
+        let err = read_lines(bytes, ReadOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "2:2: invalid UTF-8");
+    }
+
+    #[test]
+    fn read_lines_normalizes() {
+        let bytes: &[u8] = b"[A] \r\nmove 1\t\r\n\r\n1";
+        let lines = read_lines(bytes, ReadOptions::default()).unwrap();
+        assert_eq!(lines, vec!["[A]", "move 1", "", "1"]);
+
+        let lines = read_lines(&bytes[..bytes.len() - 1], ReadOptions::STRICT).unwrap();
+        assert_eq!(lines, vec!["[A] \r", "move 1\t\r", "\r"]);
+        let err = read_lines(bytes, ReadOptions::STRICT).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "4: the last line has no newline, the file may be cut short"
+        );
+    }
+
+    #[test]
+    fn file_to_vec_error_names_the_file() {
+        let filename = std::env::temp_dir().join(format!("aoc-read-{}.txt", std::process::id()));
+        fs::write(&filename, b"ok\n\xc3\n").unwrap();
+        let err = file_to_vec(filename.to_str().unwrap()).unwrap_err();
+        let expected = format!("{}:2:1: invalid UTF-8", filename.display());
+        assert_eq!(err.to_string(), expected);
+        fs::remove_file(filename).unwrap();
+    }
+
+    #[test]
+    fn input_path_falls_back_to_flat_layout() {
+        assert_eq!(input_path(2022, 1), "input/day01.txt");
+        assert_eq!(input_path(2022, 25), "input/2022/day25.txt");
+        assert_eq!(input_path(2021, 1), "input/2021/day01.txt");
+    }
+
+    #[test]
+    fn groups_skip_extra_blank_lines() {
+        let input: Vec<String> = ["", "1", "2", "", "", "3", " ", ""]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        let found: Vec<Group> = groups(&input).collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].start, found[0].lines), (1, &input[1..3]));
+        assert_eq!((found[1].start, found[1].lines), (5, &input[5..6]));
+        let numbered: Vec<usize> = found[0].enumerate().map(|(idx, _)| idx).collect();
+        assert_eq!(numbered, vec![1, 2]);
+
+        assert_eq!(groups(&[]).count(), 0);
+    }
+
     #[test]
     fn parse_string_to_int() {
         let vec_str = vec!["1".to_owned()];
@@ -47,6 +524,7 @@ mod tests {
         assert!(vec_int.is_err());
     }
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn parse_string_to_int_fails_with_parseinterror_for_nonint() {
         let vec_str = vec!["not a number".to_owned()];
 
@@ -55,4 +533,23 @@ mod tests {
 
         assert_eq!(actual_error_kind, expected_error_kind);
     }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn aoc_error_display() {
+        let err = AocError::new(2, "X 4", "unknown direction 'X'")
+            .at_column(0)
+            .in_file("input/day09.txt");
+        let expected = "input/day09.txt:3:1: unknown direction 'X' in \"X 4\"";
+        assert_eq!(err.to_string(), expected);
+
+        let err = AocError::input("the input is empty");
+        assert_eq!(err.to_string(), "the input is empty");
+    }
 }
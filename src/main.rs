@@ -1,3 +1,263 @@
-fn main() {
-    println!("Hello, world!");
+use anyhow::{bail, Context, Result};
+use aoc_rust_2022::bench::bench_day;
+use aoc_rust_2022::cli::{parse_args, Command, USAGE};
+use aoc_rust_2022::client::{fetch_input, Client, ClientConfig, Fetched, CONFIG_FILE};
+use aoc_rust_2022::days::{get_solution, SOLUTIONS};
+use aoc_rust_2022::export::export_frames;
+use aoc_rust_2022::fuzz::{fuzz, save_findings, FINDINGS_DIR};
+use aoc_rust_2022::generate::{generator, Rng};
+use aoc_rust_2022::report::{run_all, run_day, write_results, Format, PartResult};
+use aoc_rust_2022::runner::{locate_error, run_part, Input, Part};
+use aoc_rust_2022::samples::{cases_path, import_samples};
+use aoc_rust_2022::scaffold::create_day;
+use aoc_rust_2022::submit::{submit_answer, Outcome, SUBMISSIONS_FILE};
+use aoc_rust_2022::verify::{verify, KnownAnswers};
+use aoc_rust_2022::visualize::{simulation, visualize_day};
+use aoc_rust_2022::watch::watch;
+use aoc_rust_2022::{input_path, Solution};
+use std::fs;
+use std::io::{self, Write};
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::time::SystemTime;
+
+fn main() -> Result<()> {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("Error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            visualize: Some(config),
+            ..
+        } => {
+            let solution = find_solution(year, day)?;
+            let input = input.read(solution)?;
+            visualize_day(solution, &parts_to_run(part), &input, config)?;
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            export: Some(config),
+            ..
+        } => {
+            let solution = find_solution(year, day)?;
+            let input = input.read(solution)?;
+            for part in parts_to_run(part) {
+                let mut simulation = simulation(solution, part, &input)?;
+                let name = format!("{}-day{:02}-part{}", year, day, part);
+                let paths = export_frames(simulation.as_mut(), &name, &config)?;
+                match paths.as_slice() {
+                    [path] => println!("Part {}: wrote {}", part, path.display()),
+                    _ => println!(
+                        "Part {}: wrote {} images to {}",
+                        part,
+                        paths.len(),
+                        config.dir.join(format!("{}-*", name)).display()
+                    ),
+                }
+            }
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            stream,
+            format,
+            visualize: None,
+            export: None,
+        } => {
+            let solution = find_solution(year, day)?;
+            let results = run_day(solution, &parts_to_run(part), &input, stream);
+            report(format, &results)?;
+        }
+        Command::All { year, jobs, format } => {
+            let solutions: Vec<&dyn Solution> = SOLUTIONS
+                .iter()
+                .copied()
+                .filter(|solution| year.is_none_or(|year| solution.year() == year))
+                .collect();
+            if let (Some(year), true) = (year, solutions.is_empty()) {
+                bail!("No days of {} are solved yet", year);
+            }
+            report(format, &run_all(&solutions, jobs))?;
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            input,
+            config,
+        } => {
+            let solution = find_solution(year, day)?;
+            let result = bench_day(solution, &parts_to_run(part), &input, config)?;
+            print!("{}", result);
+        }
+        Command::Verify { answers } => {
+            let answers = KnownAnswers::load(&answers)?;
+            let verdicts = verify(SOLUTIONS, &answers);
+            for verdict in &verdicts {
+                println!("{}", verdict);
+            }
+            let n_failures = verdicts.iter().filter(|v| v.verdict.is_failure()).count();
+            println!("{} of {} parts failed", n_failures, verdicts.len());
+            if n_failures > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Fetch { year, day, force } => {
+            let client = Client::new(ClientConfig::load(Path::new(CONFIG_FILE))?);
+            let path = input_path(year, day);
+            match fetch_input(&client, year, day, Path::new(&path), force)? {
+                Fetched::Downloaded => println!("Downloaded {}", path),
+                Fetched::AlreadyExists => {
+                    println!("{} already exists, use --force to download it again", path)
+                }
+            }
+        }
+        Command::Submit { year, day, part } => {
+            let solution = find_solution(year, day)?;
+            let input = Input::Default.read(solution)?;
+            let answer = run_part(solution, part, &input)
+                .map_err(|err| locate_error(err, &solution.input_path()))?;
+            println!("Day {:02} part {}: submitting {}", day, part, answer);
+            let client = Client::new(ClientConfig::load(Path::new(CONFIG_FILE))?);
+            let submissions = Path::new(SUBMISSIONS_FILE);
+            let outcome = submit_answer(
+                &client,
+                submissions,
+                year,
+                day,
+                part,
+                &answer,
+                SystemTime::now(),
+            )?;
+            println!("{}", outcome);
+            if outcome != Outcome::Correct {
+                process::exit(1);
+            }
+        }
+        Command::Watch { year, day } => watch(year, day, Path::new("."))?,
+        Command::Samples {
+            year,
+            day,
+            page,
+            force,
+        } => {
+            let html = fs::read_to_string(&page)
+                .with_context(|| format!("Could not read {}", page.display()))?;
+            let samples = import_samples(Path::new("."), year, day, &html, force)?;
+            for sample in &samples {
+                println!("Wrote {}", sample.file);
+                for (part, answer) in &sample.answers {
+                    println!("  part {}: {}", part, answer);
+                }
+            }
+            println!(
+                "Added the answers to {}, check them before committing",
+                cases_path(year, day)
+            );
+        }
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let generator = generator(year, day)
+                .with_context(|| format!("There is no generator for day {} of {}", day, year))?;
+            let seed = seed.unwrap_or_else(random_seed);
+            eprintln!("Seed: {}", seed);
+            let mut stdout = io::stdout().lock();
+            for line in generator(size, &mut Rng::new(seed)) {
+                writeln!(stdout, "{}", line)?;
+            }
+        }
+        Command::Fuzz {
+            year,
+            day,
+            runs,
+            seed,
+        } => {
+            let solution = find_solution(year, day)?;
+            let seed = seed.unwrap_or_else(random_seed);
+            println!("Fuzzing day {} with seed {}", day, seed);
+            // The panics are reported as findings, so their messages would only clutter the
+            // output. Nothing else runs in the meantime, so nothing else is silenced.
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(|_| {}));
+            let stats = fuzz(solution, runs, seed);
+            panic::set_hook(hook);
+            println!(
+                "{} runs: {} solved, {} rejected, {} different panics",
+                stats.runs,
+                stats.solved,
+                stats.rejected,
+                stats.findings.len()
+            );
+            let paths = save_findings(Path::new(FINDINGS_DIR), solution, seed, &stats.findings)?;
+            for (finding, path) in stats.findings.iter().zip(paths) {
+                let how = if finding.streamed { ", streamed" } else { "" };
+                println!(
+                    "Part {}{} panicked: {}\n  input in {}",
+                    finding.part,
+                    how,
+                    finding.message,
+                    path.display()
+                );
+            }
+            if !stats.findings.is_empty() {
+                process::exit(1);
+            }
+        }
+        Command::New { year, day, title } => {
+            let title = title.unwrap_or_else(|| format!("Day {}", day));
+            let new_day = create_day(Path::new("."), year, day, &title)?;
+            println!("Created {}", new_day.module.display());
+            for created in new_day.sample.iter().chain(&new_day.cases) {
+                println!("Created {}", created.display());
+            }
+            println!("Added day {} of {} to src/days/mod.rs", day, year);
+        }
+        Command::Help => println!("{}", USAGE),
+    }
+    Ok(())
+}
+
+fn find_solution(year: u16, day: u8) -> Result<&'static dyn Solution> {
+    get_solution(year, day).with_context(|| format!("Day {} of {} is not solved yet", day, year))
+}
+
+/// A seed for when none was given, which is printed so the run can be repeated
+fn random_seed() -> u64 {
+    let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH);
+    now.map_or(0, |now| now.as_nanos() as u64)
+}
+
+/// The given part, or both if none was given
+fn parts_to_run(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+/// Write `results` to stdout, and exit with an error if any part failed
+fn report(format: Format, results: &[PartResult]) -> Result<()> {
+    write_results(&mut io::stdout().lock(), format, results)?;
+    if results.iter().any(PartResult::is_failure) {
+        process::exit(1);
+    }
+    Ok(())
 }
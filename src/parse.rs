@@ -0,0 +1,288 @@
+//! Small parser combinators for the lines of puzzle inputs.
+//!
+//! A parser is anything that implements [`Parser`], which closures taking a [`Cursor`] do. The
+//! functions in this module build parsers, and [`parse_line`] runs one on a whole line:
+//!
+//! ```
+//! use aoc_rust_2022::parse::{int, literal, parse_line, Cursor};
+//!
+//! let pair = parse_line("move 3 from 1", |c: &mut Cursor| {
+//!     c.parse(literal("move "))?;
+//!     let n: usize = c.parse(int())?;
+//!     c.parse(literal(" from "))?;
+//!     Ok((n, c.parse(int::<usize>())?))
+//! });
+//! assert_eq!(pair, Ok((3, 1)));
+//! ```
+use crate::AocError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where parsing failed, and what was expected there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Counted in chars from 0
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Turn this into an [`AocError`] for the line at `line_idx`
+    pub fn in_line(self, line_idx: usize, line: &str) -> AocError {
+        AocError::new(line_idx, line, format!("expected {}", self.expected)).at_column(self.column)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: expected {}", self.column + 1, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The text being parsed, and how far parsing has come
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    text: &'a str,
+    /// In bytes
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Cursor { text, pos: 0 }
+    }
+
+    /// The text that is not parsed yet
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// Run `parser` from here
+    pub fn parse<T>(&mut self, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+        parser.parse(self)
+    }
+
+    /// An error at the current position
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            column: self.text[..self.pos].chars().count(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Take the longest prefix of the rest where every char matches `pred`
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+}
+
+/// Something that parses a `T` from a [`Cursor`], moving it past what was parsed. `T` can borrow
+/// from the text, which lives for `'a`.
+pub trait Parser<'a, T> {
+    fn parse(&self, cursor: &mut Cursor<'a>) -> Result<T, ParseError>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&mut Cursor<'a>) -> Result<T, ParseError>,
+{
+    fn parse(&self, cursor: &mut Cursor<'a>) -> Result<T, ParseError> {
+        self(cursor)
+    }
+}
+
+/// Run `parser` on all of `line`, failing if anything is left over
+pub fn parse_line<'a, T>(line: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let value = parser.parse(&mut cursor)?;
+    if !cursor.is_at_end() {
+        return Err(cursor.error("the end of the line"));
+    }
+    Ok(value)
+}
+
+/// Exactly the text `expected`
+pub fn literal<'a>(expected: &'static str) -> impl Fn(&mut Cursor<'a>) -> Result<(), ParseError> {
+    move |cursor| {
+        if cursor.rest().starts_with(expected) {
+            cursor.pos += expected.len();
+            Ok(())
+        } else {
+            Err(cursor.error(format!("{:?}", expected)))
+        }
+    }
+}
+
+/// An integer in decimal, with a `-` in front if it is negative
+pub fn int<'a, T: FromStr>() -> impl Fn(&mut Cursor<'a>) -> Result<T, ParseError> {
+    |cursor| {
+        let start = cursor.clone();
+        let sign = if cursor.rest().starts_with('-') { 1 } else { 0 };
+        cursor.pos += sign;
+        let digits = cursor.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            *cursor = start;
+            return Err(cursor.error("an integer"));
+        }
+        let text = &start.rest()[..sign + digits.len()];
+        text.parse()
+            .map_err(|_| start.error("an integer that fits"))
+    }
+}
+
+/// A name made of letters, digits and `_`, that does not start with a digit
+pub fn identifier<'a>() -> impl Fn(&mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    |cursor| match cursor.rest().chars().next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {
+            Ok(cursor.take_while(|c| c.is_alphanumeric() || c == '_'))
+        }
+        _ => Err(cursor.error("an identifier")),
+    }
+}
+
+/// Anything up to the next whitespace, like a file name
+pub fn word<'a>() -> impl Fn(&mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    |cursor| {
+        let word = cursor.take_while(|c| !c.is_whitespace());
+        if word.is_empty() {
+            Err(cursor.error("a word"))
+        } else {
+            Ok(word)
+        }
+    }
+}
+
+/// Skip any spaces and tabs, including none
+pub fn ws<'a>() -> impl Fn(&mut Cursor<'a>) -> Result<(), ParseError> {
+    |cursor| {
+        cursor.take_while(|c| c == ' ' || c == '\t');
+        Ok(())
+    }
+}
+
+/// `parser` if it matches, or `None` without moving the cursor if it doesn't
+pub fn optional<'a, T>(
+    parser: impl Parser<'a, T>,
+) -> impl Fn(&mut Cursor<'a>) -> Result<Option<T>, ParseError> {
+    move |cursor| {
+        let start = cursor.clone();
+        match parser.parse(cursor) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *cursor = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// `parser` after `prefix`, keeping only what `parser` returns
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Fn(&mut Cursor<'a>) -> Result<T, ParseError> {
+    move |cursor| {
+        prefix.parse(cursor)?;
+        parser.parse(cursor)
+    }
+}
+
+/// One or more of `item`, with `separator` between them
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Fn(&mut Cursor<'a>) -> Result<Vec<T>, ParseError> {
+    move |cursor| {
+        let mut items = vec![item.parse(cursor)?];
+        loop {
+            let before_separator = cursor.clone();
+            if separator.parse(cursor).is_err() {
+                *cursor = before_separator;
+                return Ok(items);
+            }
+            items.push(item.parse(cursor)?);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_literals_and_ints() {
+        let parser = |c: &mut Cursor| {
+            c.parse(literal("addx"))?;
+            c.parse(ws())?;
+            c.parse(int::<i32>())
+        };
+        assert_eq!(parse_line("addx -12", parser), Ok(-12));
+        assert_eq!(parse_line("addx   3", parser), Ok(3));
+
+        let err = parse_line("addy 3", parser).unwrap_err();
+        assert_eq!(err.column, 0);
+        assert_eq!(err.expected, "\"addx\"");
+        let err = parse_line("addx x", parser).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (5, "an integer"));
+        let err = parse_line("addx 3 ", parser).unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (6, "the end of the line")
+        );
+    }
+
+    #[test]
+    fn int_that_does_not_fit() {
+        let err = parse_line("-1", int::<usize>()).unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (0, "an integer that fits")
+        );
+        assert_eq!(parse_line("300", int::<u8>()).unwrap_err().column, 0);
+    }
+
+    #[test]
+    fn identifiers_and_words() {
+        assert_eq!(parse_line("cd_2", identifier()), Ok("cd_2"));
+        assert!(parse_line("2cd", identifier()).is_err());
+        let parser = preceded(literal("dir "), word());
+        assert_eq!(parse_line("dir a.txt", parser), Ok("a.txt"));
+    }
+
+    #[test]
+    fn separated_lists() {
+        let parser = separated(int::<u32>(), literal(", "));
+        assert_eq!(parse_line("1, 2, 3", &parser), Ok(vec![1, 2, 3]));
+        let err = parse_line("1, 2,", &parser).unwrap_err();
+        assert_eq!(err.column, 4);
+    }
+
+    #[test]
+    fn optional_does_not_move_on_failure() {
+        let parser = |c: &mut Cursor| {
+            let sign = c.parse(optional(literal("+")))?;
+            Ok((sign.is_some(), c.parse(int::<i32>())?))
+        };
+        assert_eq!(parse_line("+4", parser), Ok((true, 4)));
+        assert_eq!(parse_line("4", parser), Ok((false, 4)));
+    }
+
+    #[test]
+    fn error_in_line() {
+        let err = parse_line("noop 3", literal("noop")).unwrap_err();
+        let err = err.in_line(4, "noop 3");
+        assert_eq!(
+            err.to_string(),
+            "5:5: expected the end of the line in \"noop 3\""
+        );
+    }
+}
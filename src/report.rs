@@ -0,0 +1,416 @@
+//! Results of running days, written as text for people or as JSON or CSV for other tools
+use crate::runner::{locate_error, run_part, stream_part, Input, Part};
+use crate::verify::catch_panic;
+use crate::{Answer, Solution, DEFAULT_YEAR};
+use anyhow::bail;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How to write the results of `run` and `all`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("'{}' is not a valid format, expected json, csv or text", s),
+        }
+    }
+}
+
+/// The outcome of running one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part: Part,
+    /// The answer, or the error message if the part failed
+    pub answer: Result<Answer, String>,
+    /// Parsing and solving, but not reading the input unless it was streamed
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn is_failure(&self) -> bool {
+        self.answer.is_err()
+    }
+}
+
+/// Run `parts` of `solution`, carrying on with the next part if one fails or panics. With
+/// `stream`, each part reads the input one line at a time, see [`stream_part`].
+pub fn run_day(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: &Input,
+    stream: bool,
+) -> Vec<PartResult> {
+    let input_name = input.name(solution);
+    let describe = |err| format!("{:#}", locate_error(err, &input_name));
+    let outcome = |answer: Result<anyhow::Result<Answer>, String>| match answer {
+        Ok(answer) => answer.map_err(describe),
+        Err(message) => Err(format!("panicked: {}", message)),
+    };
+    let result = |part, answer, elapsed| PartResult {
+        year: solution.year(),
+        day: solution.day(),
+        title: solution.title(),
+        part,
+        answer,
+        elapsed,
+    };
+    if stream {
+        return parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = outcome(catch_panic(|| stream_part(solution, part, input)));
+                result(part, answer, start.elapsed())
+            })
+            .collect();
+    }
+    let lines = match input.read(solution) {
+        Ok(lines) => lines,
+        Err(err) => {
+            let message = describe(err);
+            return parts
+                .iter()
+                .map(|&part| result(part, Err(message.clone()), Duration::ZERO))
+                .collect();
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = outcome(catch_panic(|| run_part(solution, part, &lines)));
+            result(part, answer, start.elapsed())
+        })
+        .collect()
+}
+
+/// Run both parts of every solution on its real input, on `jobs` threads. Each part is a separate
+/// job, but the results are in the order of `solutions` however the jobs finish.
+pub fn run_all(solutions: &[&dyn Solution], jobs: usize) -> Vec<PartResult> {
+    if jobs <= 1 {
+        return solutions
+            .iter()
+            .flat_map(|solution| run_day(*solution, &Part::BOTH, &Input::Default, false))
+            .collect();
+    }
+    let tasks: Vec<(&dyn Solution, Part)> = solutions
+        .iter()
+        .flat_map(|&solution| Part::BOTH.map(|part| (solution, part)))
+        .collect();
+    let next_task = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; tasks.len()]);
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            scope.spawn(|| loop {
+                let idx = next_task.fetch_add(1, Ordering::Relaxed);
+                let Some(&(solution, part)) = tasks.get(idx) else {
+                    break;
+                };
+                let result = run_day(solution, &[part], &Input::Default, false).pop();
+                // Panics are caught in `run_day`, so the lock is never poisoned
+                results.lock().unwrap()[idx] = result;
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result: Option<PartResult>| result.expect("every job is run"))
+        .collect()
+}
+
+/// Write `results` to `out` in `format`
+pub fn write_results(
+    out: &mut impl Write,
+    format: Format,
+    results: &[PartResult],
+) -> io::Result<()> {
+    match format {
+        Format::Text => write_text(out, results),
+        Format::Json => write_json(out, results),
+        Format::Csv => write_csv(out, results),
+    }
+}
+
+/// A heading for each day, then one line per part. Drawings start on their own line, so they
+/// aren't skewed by the label. The year is only in the heading if it is not [`DEFAULT_YEAR`].
+fn write_text(out: &mut impl Write, results: &[PartResult]) -> io::Result<()> {
+    let mut last_day = None;
+    for result in results {
+        if last_day != Some((result.year, result.day)) {
+            if result.year != DEFAULT_YEAR {
+                write!(out, "{} ", result.year)?;
+            }
+            writeln!(out, "Day {:02}: {}", result.day, result.title)?;
+            last_day = Some((result.year, result.day));
+        }
+        match &result.answer {
+            Ok(answer) => {
+                let answer = answer.to_string();
+                if answer.contains('\n') {
+                    writeln!(out, "Part {}:\n{}", result.part, answer)?;
+                } else {
+                    writeln!(out, "Part {}: {}", result.part, answer)?;
+                }
+            }
+            Err(message) => writeln!(out, "Part {}: Error: {}", result.part, message)?,
+        }
+    }
+    Ok(())
+}
+
+/// An array with one object per part. Int answers are numbers and text answers are strings,
+/// and `answer` and `answer_type` are `null` when `error` is set.
+fn write_json(out: &mut impl Write, results: &[PartResult]) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (idx, result) in results.iter().enumerate() {
+        let (answer, answer_type, error) = match &result.answer {
+            Ok(Answer::Int(value)) => (value.to_string(), "\"int\"", "null".to_owned()),
+            Ok(Answer::Text(text)) => (json_string(text), "\"text\"", "null".to_owned()),
+            Err(message) => ("null".to_owned(), "null", json_string(message)),
+        };
+        let separator = if idx + 1 < results.len() { "," } else { "" };
+        writeln!(
+            out,
+            "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"answer_type\": {}, \"time_ms\": {}, \"error\": {}}}{}",
+            result.year,
+            result.day,
+            result.part,
+            answer,
+            answer_type,
+            millis(result.elapsed),
+            error,
+            separator
+        )?;
+    }
+    writeln!(out, "]")
+}
+
+/// A header row, then one row per part. Fields that don't apply are left empty.
+fn write_csv(out: &mut impl Write, results: &[PartResult]) -> io::Result<()> {
+    writeln!(out, "year,day,part,answer,answer_type,time_ms,error")?;
+    for result in results {
+        let (answer, answer_type, error) = match &result.answer {
+            Ok(answer @ Answer::Int(_)) => (answer.to_string(), "int", String::new()),
+            Ok(answer @ Answer::Text(_)) => (answer.to_string(), "text", String::new()),
+            Err(message) => (String::new(), "", message.clone()),
+        };
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            result.year,
+            result.day,
+            result.part,
+            csv_field(&answer),
+            answer_type,
+            millis(result.elapsed),
+            csv_field(&error)
+        )?;
+    }
+    Ok(())
+}
+
+fn millis(elapsed: Duration) -> String {
+    format!("{:.3}", elapsed.as_secs_f64() * 1000.0)
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quote `text` if it contains anything that would break the row, doubling any quotes
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::get_solution;
+
+    fn sample_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: 2022,
+                day: 5,
+                title: "Supply Stacks",
+                part: Part::One,
+                answer: Ok(Answer::Text("CMZ".to_owned())),
+                elapsed: Duration::from_micros(1500),
+            },
+            PartResult {
+                year: 2022,
+                day: 10,
+                title: "Cathode-Ray Tube",
+                part: Part::One,
+                answer: Ok(Answer::Int(13140)),
+                elapsed: Duration::from_micros(20),
+            },
+            PartResult {
+                year: 2022,
+                day: 10,
+                title: "Cathode-Ray Tube",
+                part: Part::Two,
+                answer: Err("input/day10.txt:3: unknown \"command\"".to_owned()),
+                elapsed: Duration::ZERO,
+            },
+        ]
+    }
+
+    fn written(format: Format, results: &[PartResult]) -> String {
+        let mut out = Vec::new();
+        write_results(&mut out, format, results).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!("csv".parse::<Format>().unwrap(), Format::Csv);
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn write_text_results() {
+        let expected = "\
+Day 05: Supply Stacks
+Part 1: CMZ
+Day 10: Cathode-Ray Tube
+Part 1: 13140
+Part 2: Error: input/day10.txt:3: unknown \"command\"
+";
+        assert_eq!(written(Format::Text, &sample_results()), expected);
+    }
+
+    #[test]
+    fn write_json_results() {
+        let expected = r#"[
+  {"year": 2022, "day": 5, "part": 1, "answer": "CMZ", "answer_type": "text", "time_ms": 1.500, "error": null},
+  {"year": 2022, "day": 10, "part": 1, "answer": 13140, "answer_type": "int", "time_ms": 0.020, "error": null},
+  {"year": 2022, "day": 10, "part": 2, "answer": null, "answer_type": null, "time_ms": 0.000, "error": "input/day10.txt:3: unknown \"command\""}
+]
+"#;
+        assert_eq!(written(Format::Json, &sample_results()), expected);
+        assert_eq!(written(Format::Json, &[]), "[\n]\n");
+    }
+
+    #[test]
+    fn write_csv_results() {
+        let expected = "\
+year,day,part,answer,answer_type,time_ms,error
+2022,5,1,CMZ,text,1.500,
+2022,10,1,13140,int,0.020,
+2022,10,2,,,0.000,\"input/day10.txt:3: unknown \"\"command\"\"\"
+";
+        assert_eq!(written(Format::Csv, &sample_results()), expected);
+    }
+
+    #[test]
+    fn escape_drawings() {
+        assert_eq!(json_string("#.\n\"\\\u{1}"), r##""#.\n\"\\\u0001""##);
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+        assert_eq!(csv_field("plain"), "plain");
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        fn day(&self) -> u8 {
+            25
+        }
+
+        fn title(&self) -> &'static str {
+            "Panics"
+        }
+
+        fn part_1(&self, _input: &[String]) -> anyhow::Result<Answer> {
+            panic!("Could not find any overlap")
+        }
+
+        fn part_2(&self, _input: &[String]) -> anyhow::Result<Answer> {
+            Ok(Answer::Int(2))
+        }
+
+        fn input_path(&self) -> String {
+            "input/day01_sample.txt".to_owned()
+        }
+    }
+
+    #[test]
+    fn run_all_keeps_the_order() {
+        let solutions = [
+            &Panics as &dyn Solution,
+            get_solution(2022, 1).unwrap(),
+            &Panics,
+        ];
+        let results = run_all(&solutions, 4);
+        let order: Vec<(u8, Part)> = results.iter().map(|r| (r.day, r.part)).collect();
+        let expected: Vec<(u8, Part)> = [25, 1, 25]
+            .into_iter()
+            .flat_map(|day| Part::BOTH.map(|part| (day, part)))
+            .collect();
+        assert_eq!(order, expected);
+        assert_eq!(
+            results[0].answer,
+            Err("panicked: Could not find any overlap".to_owned())
+        );
+        assert_eq!(results[1].answer, Ok(Answer::Int(2)));
+
+        let answers = |results: Vec<PartResult>| -> Vec<_> {
+            results.into_iter().map(|result| result.answer).collect()
+        };
+        assert_eq!(answers(results), answers(run_all(&solutions, 1)));
+    }
+
+    #[test]
+    fn run_day_keeps_going_after_an_error() {
+        let solution = get_solution(2022, 1).unwrap();
+        let input = Input::File("input/day01_sample.txt".into());
+        let results = run_day(solution, &Part::BOTH, &input, false);
+        assert_eq!(results[0].answer, Ok(Answer::Int(24000)));
+        assert_eq!(results[1].answer, Ok(Answer::Int(45000)));
+
+        let input = Input::File("input/not-a-real-file.txt".into());
+        let results = run_day(solution, &Part::BOTH, &input, true);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(PartResult::is_failure));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    file_to_vec, lines, lines_from_reader, read_lines, Answer, AocError, LineStream, ReadOptions,
+    Solution,
+};
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// One of the two parts every puzzle has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("'{}' is not a valid part, expected 1 or 2", s),
+        }
+    }
+}
+
+/// Where to read the puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// The real input for the day, see [`Solution::input_path`]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// How to refer to this input in messages
+    pub fn name(&self, solution: &dyn Solution) -> String {
+        match self {
+            Input::Default => solution.input_path(),
+            Input::File(path) => path.display().to_string(),
+            Input::Stdin => "<stdin>".to_owned(),
+        }
+    }
+
+    /// Read the input for `solution` into lines
+    pub fn read(&self, solution: &dyn Solution) -> Result<Vec<String>> {
+        match self {
+            Input::Default => read_file(&solution.input_path()),
+            Input::File(path) => read_file(&path.to_string_lossy()),
+            Input::Stdin => read_lines(io::stdin().lock(), ReadOptions::default())
+                .context("Could not read input from stdin"),
+        }
+    }
+
+    /// Open the input for `solution` to read it a byte at a time, see [`Solution::stream_bytes`]
+    pub fn reader(&self, solution: &dyn Solution) -> Result<Box<dyn BufRead>> {
+        let open = |path: String| -> Result<Box<dyn BufRead>> {
+            let file = File::open(&path).with_context(|| format!("Could not read {}", path))?;
+            Ok(Box::new(BufReader::new(file)))
+        };
+        match self {
+            Input::Default => open(solution.input_path()),
+            Input::File(path) => open(path.display().to_string()),
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    /// Read the input for `solution` one line at a time, see [`Solution::stream`]
+    pub fn lines(&self, solution: &dyn Solution) -> Result<Box<LineStream<'static>>> {
+        Ok(match self {
+            Input::Default => Box::new(open_lines(&solution.input_path())?),
+            Input::File(path) => Box::new(open_lines(&path.to_string_lossy())?),
+            Input::Stdin => Box::new(lines_from_reader(
+                io::stdin().lock(),
+                ReadOptions::default(),
+            )),
+        })
+    }
+}
+
+fn open_lines(filename: &str) -> Result<impl Iterator<Item = io::Result<String>>> {
+    lines(filename).with_context(|| format!("Could not read {}", filename))
+}
+
+fn read_file(filename: &str) -> Result<Vec<String>> {
+    file_to_vec(filename).with_context(|| format!("Could not read {}", filename))
+}
+
+/// Run a single `part` of `solution` on already read `input`
+pub fn run_part(solution: &dyn Solution, part: Part, input: &[String]) -> Result<Answer> {
+    match part {
+        Part::One => solution.part_1(input),
+        Part::Two => solution.part_2(input),
+    }
+}
+
+/// Run a single `part` of `solution`, reading `input` a byte at a time on days that can, and
+/// otherwise one line at a time
+pub fn stream_part(solution: &dyn Solution, part: Part, input: &Input) -> Result<Answer> {
+    let mut reader = input.reader(solution)?;
+    if let Some(answer) = solution.stream_bytes(part, &mut reader) {
+        return answer;
+    }
+    // Nothing was read, so the input can be opened again to read lines
+    drop(reader);
+    let mut lines = input.lines(solution)?;
+    solution.stream(part, &mut lines).with_context(|| {
+        format!(
+            "Day {} can not be solved one line at a time",
+            solution.day()
+        )
+    })?
+}
+
+/// Like [`stream_part`], for input that is already open
+pub fn stream_reader(
+    solution: &dyn Solution,
+    part: Part,
+    reader: &mut dyn BufRead,
+) -> Option<Result<Answer>> {
+    if let Some(answer) = solution.stream_bytes(part, reader) {
+        return Some(answer);
+    }
+    solution.stream(part, &mut lines_from_reader(reader, ReadOptions::default()))
+}
+
+/// Add the name of the input file to `err`, if it is an [`AocError`]
+pub fn locate_error(err: anyhow::Error, input_name: &str) -> anyhow::Error {
+    match err.downcast::<AocError>() {
+        Ok(err) => err.in_file(input_name).into(),
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::get_solution;
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn run_part_on_file_input() {
+        let solution = get_solution(2022, 1).unwrap();
+        let input = Input::File("input/day01_sample.txt".into())
+            .read(solution)
+            .unwrap();
+        let result = run_part(solution, Part::Two, &input).unwrap();
+        assert_eq!(result, Answer::Int(45000));
+    }
+
+    #[test]
+    fn stream_part_on_file_input() {
+        let input = Input::File("input/day01_sample.txt".into());
+        let result = stream_part(get_solution(2022, 1).unwrap(), Part::One, &input).unwrap();
+        assert_eq!(result, Answer::Int(24000));
+        assert!(stream_part(get_solution(2022, 5).unwrap(), Part::One, &input).is_err());
+    }
+
+    #[test]
+    fn missing_input_file_is_an_error() {
+        let solution = get_solution(2022, 1).unwrap();
+        let result = Input::File("input/not-a-real-file.txt".into()).read(solution);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn locate_aoc_error() {
+        let err = AocError::new(0, "X 4", "unknown direction").into();
+        let located = locate_error(err, "input/day09.txt");
+        assert_eq!(
+            located.to_string(),
+            "input/day09.txt:1: unknown direction in \"X 4\""
+        );
+    }
+}
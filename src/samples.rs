@@ -0,0 +1,597 @@
+//! Sample inputs and the answers the puzzle descriptions give for them
+use crate::html::to_text;
+use crate::runner::{run_part, Part};
+use crate::{file_to_vec, Solution, DEFAULT_YEAR};
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// A `<pre><code>` block on a puzzle page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// Where the block starts in its section, to compare with [`Section::answer`]
+    pub position: usize,
+    pub text: String,
+    /// Whether the paragraph before it mentions an example. Blocks that don't are usually
+    /// drawings of what happens, not an input.
+    pub is_example: bool,
+}
+
+/// The description of one part of a puzzle, which is an `<article>` on the page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section {
+    pub blocks: Vec<Block>,
+    /// The last emphasized `<code>`, and where it is. This is the answer for the example.
+    pub answer: Option<(usize, String)>,
+}
+
+/// Find the sections of a saved puzzle page. A page saved after solving part 1 has both parts.
+pub fn parse_page(html: &str) -> Vec<Section> {
+    let articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .map(|article| article.split("</article>").next().unwrap_or(article))
+        .collect();
+    if articles.is_empty() {
+        vec![parse_section(html)]
+    } else {
+        articles.into_iter().map(parse_section).collect()
+    }
+}
+
+fn parse_section(html: &str) -> Section {
+    let blocks = tagged(html, "<pre><code>", "</code></pre>")
+        .map(|(position, inner)| {
+            let paragraph = html[..position]
+                .rfind("<p>")
+                .map_or("", |start| &html[start..position]);
+            Block {
+                position,
+                text: to_text(inner),
+                is_example: to_text(paragraph).to_lowercase().contains("example"),
+            }
+        })
+        .collect();
+    let answer = tagged(html, "<code><em>", "</em></code>")
+        .chain(tagged(html, "<em><code>", "</code></em>"))
+        .max_by_key(|(position, _)| *position)
+        .map(|(position, inner)| (position, to_text(inner)));
+    Section { blocks, answer }
+}
+
+/// The position and inner text of every `open ... close` in `html`
+fn tagged<'a>(
+    html: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    let mut idx = 0;
+    std::iter::from_fn(move || {
+        let start = idx + html[idx..].find(open)?;
+        let inner_start = start + open.len();
+        let inner_end = inner_start + html[inner_start..].find(close)?;
+        idx = inner_end + close.len();
+        Some((start, &html[inner_start..inner_end]))
+    })
+}
+
+/// A sample input to write to a file, and the answers it should give
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub file: String,
+    pub text: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Where the `n`th sample for `day` of `year` is stored, counted from 0. Like the real input, the
+/// samples for [`DEFAULT_YEAR`] are directly in `input/`.
+pub fn sample_path(year: u16, day: u8, n: usize) -> String {
+    let dir = if year == DEFAULT_YEAR {
+        "input".to_owned()
+    } else {
+        format!("input/{}", year)
+    };
+    match n {
+        0 => format!("{}/day{:02}_sample.txt", dir, day),
+        n => format!("{}/day{:02}_sample_{}.txt", dir, day, n + 1),
+    }
+}
+
+/// Match the answer of each part with the example input it is for: the last block before the
+/// answer whose paragraph mentions an example, in that part or an earlier one. If no paragraph
+/// mentions an example, the first block on the page is used.
+pub fn find_samples(year: u16, day: u8, sections: &[Section]) -> Result<Vec<Sample>> {
+    let first_block = sections
+        .iter()
+        .flat_map(|section| &section.blocks)
+        .next()
+        .context("There are no <pre><code> blocks on the page")?;
+    let mut samples: Vec<Sample> = Vec::new();
+    let mut example = None;
+    for (section, part) in sections.iter().zip(Part::BOTH) {
+        let answer_position = section.answer.as_ref().map_or(usize::MAX, |(pos, _)| *pos);
+        let last_example = section
+            .blocks
+            .iter()
+            .rev()
+            .find(|block| block.is_example && block.position < answer_position);
+        example = last_example.or(example);
+        let Some((_, answer)) = &section.answer else {
+            continue;
+        };
+        let text = normalize(&example.unwrap_or(first_block).text);
+        let idx = match samples.iter().position(|sample| sample.text == text) {
+            Some(idx) => idx,
+            None => {
+                samples.push(Sample {
+                    file: sample_path(year, day, samples.len()),
+                    text,
+                    answers: Vec::new(),
+                });
+                samples.len() - 1
+            }
+        };
+        samples[idx].answers.push((part, answer.clone()));
+    }
+    if samples.is_empty() {
+        bail!("There are no emphasized answers on the page");
+    }
+    Ok(samples)
+}
+
+/// End the text with exactly one newline, like the input files
+fn normalize(text: &str) -> String {
+    text.trim_end_matches('\n').to_owned() + "\n"
+}
+
+/// Where the sample cases for `day` of `year` are listed, next to the samples
+pub fn cases_path(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("input/day{:02}_cases.txt", day)
+    } else {
+        format!("input/{}/day{:02}_cases.txt", year, day)
+    }
+}
+
+/// The input of a sample case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaseInput {
+    /// A sample file, relative to the root of the repository
+    File(String),
+    /// Lines written out in the case file, for short samples
+    Inline(Vec<String>),
+}
+
+/// A sample input and the answers the puzzle description gives for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampleCase {
+    /// Also the name of the test that checks it
+    pub name: String,
+    pub input: CaseInput,
+    pub answers: Vec<(Part, String)>,
+}
+
+impl SampleCase {
+    pub fn read_input(&self) -> Result<Vec<String>> {
+        match &self.input {
+            CaseInput::File(file) => {
+                file_to_vec(file).with_context(|| format!("Could not read {}", file))
+            }
+            CaseInput::Inline(lines) => Ok(lines.clone()),
+        }
+    }
+
+    /// Run `solution` on the input and compare with every answer
+    pub fn check(&self, solution: &dyn Solution) -> Result<()> {
+        if self.answers.is_empty() {
+            bail!("Case '{}' has no answers to check", self.name);
+        }
+        let input = self.read_input()?;
+        for (part, expected) in &self.answers {
+            let actual = run_part(solution, *part, &input)
+                .with_context(|| format!("Part {} of case '{}' failed", part, self.name))?;
+            if actual.to_string() != *expected {
+                bail!(
+                    "Part {} of case '{}': expected {:?} but got {:?}",
+                    part,
+                    self.name,
+                    expected,
+                    actual.to_string()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The sample cases of a day, see [`cases_path`].
+///
+/// Each case starts with `== <name>`, followed by `part 1: <answer>` and `part 2: <answer>` for
+/// the parts the puzzle gives an answer for, where `\n` is a line break. The input is either
+/// `file: <path>`, or `input:` followed by the lines of the input up to the next case.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SampleCases(pub Vec<SampleCase>);
+
+impl SampleCases {
+    pub fn parse(text: &str) -> Result<SampleCases> {
+        let mut cases = Vec::new();
+        // The case being read, and whether its inline input has started
+        let mut current: Option<PartialCase> = None;
+        let mut in_input = false;
+        for (idx, line) in text.lines().enumerate() {
+            if let Some(name) = line.strip_prefix("== ") {
+                cases.extend(current.take().map(PartialCase::finish).transpose()?);
+                current = Some(PartialCase {
+                    name: name.trim().to_owned(),
+                    input: None,
+                    answers: Vec::new(),
+                });
+                in_input = false;
+                continue;
+            }
+            if let Some(PartialCase {
+                input: Some(CaseInput::Inline(lines)),
+                ..
+            }) = &mut current
+            {
+                if in_input {
+                    lines.push(line.to_owned());
+                    continue;
+                }
+            }
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some(PartialCase { input, answers, .. }) = &mut current else {
+                bail!("Line {}: expected '== <name>' to start a case", idx + 1);
+            };
+            if line == "input:" {
+                *input = Some(CaseInput::Inline(Vec::new()));
+                in_input = true;
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .with_context(|| format!("Line {}: expected 'key: value'", idx + 1))?;
+            let value = value.trim();
+            match key.trim().strip_prefix("part ") {
+                Some(part) => {
+                    let part = part
+                        .parse()
+                        .with_context(|| format!("Line {}: bad part", idx + 1))?;
+                    answers.push((part, value.replace("\\n", "\n")));
+                }
+                None if key.trim() == "file" => *input = Some(CaseInput::File(value.to_owned())),
+                None => bail!("Line {}: unknown key '{}'", idx + 1, key.trim()),
+            }
+        }
+        cases.extend(current.map(PartialCase::finish).transpose()?);
+        let mut names: Vec<&str> = cases.iter().map(|case| case.name.as_str()).collect();
+        names.sort_unstable();
+        if let Some(pair) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+            bail!("There is more than one case named '{}'", pair[0]);
+        }
+        Ok(SampleCases(cases))
+    }
+
+    /// Read the cases in `path`, or none if it doesn't exist yet
+    pub fn load(path: &Path) -> Result<SampleCases> {
+        if !path.exists() {
+            return Ok(SampleCases::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Could not read sample cases from {}", path.display()))?;
+        SampleCases::parse(&text).with_context(|| format!("Error in {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SampleCase> {
+        self.0.iter().find(|case| case.name == name)
+    }
+
+    /// Set the answer for `part` on the sample in `file`, adding a case named `name` if no case
+    /// reads that file yet
+    pub fn set_answer(&mut self, name: &str, file: &str, part: Part, answer: &str) {
+        let input = CaseInput::File(file.to_owned());
+        let idx = match self.0.iter().position(|case| case.input == input) {
+            Some(idx) => idx,
+            None => {
+                self.0.push(SampleCase {
+                    name: name.to_owned(),
+                    input,
+                    answers: Vec::new(),
+                });
+                self.0.len() - 1
+            }
+        };
+        let answers = &mut self.0[idx].answers;
+        answers.retain(|(other, _)| *other != part);
+        answers.push((part, answer.to_owned()));
+        answers.sort();
+    }
+}
+
+/// A case that [`SampleCases::parse`] is still reading
+struct PartialCase {
+    name: String,
+    input: Option<CaseInput>,
+    answers: Vec<(Part, String)>,
+}
+
+impl PartialCase {
+    /// Check that the case has an input, and drop the blank lines that separate an inline input
+    /// from the next case
+    fn finish(self) -> Result<SampleCase> {
+        let input = match self.input {
+            Some(CaseInput::Inline(mut lines)) => {
+                while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                    lines.pop();
+                }
+                CaseInput::Inline(lines)
+            }
+            Some(input) => input,
+            None => bail!("Case '{}' has no 'file:' or 'input:'", self.name),
+        };
+        Ok(SampleCase {
+            name: self.name,
+            input,
+            answers: self.answers,
+        })
+    }
+}
+
+impl fmt::Display for SampleCases {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, case) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "== {}", case.name)?;
+            if let CaseInput::File(file) = &case.input {
+                writeln!(f, "file: {}", file)?;
+            }
+            for (part, answer) in &case.answers {
+                writeln!(f, "part {}: {}", part, answer.replace('\n', "\\n"))?;
+            }
+            if let CaseInput::Inline(lines) = &case.input {
+                writeln!(f, "input:")?;
+                for line in lines {
+                    writeln!(f, "{}", line)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the samples found on the saved puzzle page `html` to files under `root`, and add their
+/// answers to the cases of the day. A sample file that exists with other content is only replaced
+/// if `force` is set.
+pub fn import_samples(
+    root: &Path,
+    year: u16,
+    day: u8,
+    html: &str,
+    force: bool,
+) -> Result<Vec<Sample>> {
+    let samples = find_samples(year, day, &parse_page(html))?;
+    for sample in &samples {
+        let path = root.join(&sample.file);
+        if let Ok(existing) = fs::read_to_string(&path) {
+            if existing != sample.text && !force {
+                bail!(
+                    "{} already exists with other content, use --force to replace it",
+                    sample.file
+                );
+            }
+        }
+    }
+    let cases_file = root.join(cases_path(year, day));
+    let mut cases = SampleCases::load(&cases_file)?;
+    for sample in &samples {
+        let path = root.join(&sample.file);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &sample.text)
+            .with_context(|| format!("Could not write {}", path.display()))?;
+        // Named after the file, so `day09_sample_2.txt` is `sample_2`
+        let name = sample
+            .file
+            .rsplit_once("_sample")
+            .map_or("sample", |(_, rest)| rest);
+        let name = format!("sample{}", name.trim_end_matches(".txt"));
+        for (part, answer) in &sample.answers {
+            cases.set_answer(&name, &sample.file, *part, answer);
+        }
+    }
+    cases.save(&cases_file)?;
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{get_solution, SOLUTIONS};
+
+    /// Like a puzzle page saved after solving part 1, cut down to what matters
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Rope Bridge ---</h2>
+<p>For example:</p>
+<pre><code>R 4
+U 4
+</code></pre>
+<p>This series of motions moves the head as follows:</p>
+<pre><code>......
+<em>H</em>.....
+</code></pre>
+<p>So, there are <code><em>13</em></code> positions the tail visited at least once.</p>
+</article>
+<p>Your puzzle answer was <code>6642</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the same series of motions as the above example:</p>
+<pre><code>..H...
+</code></pre>
+<p>Now, the tail visits <code><em>1</em></code> position. Consider a larger example:</p>
+<pre><code>R 5
+U 8
+</code></pre>
+<p>Now, the tail visits <code><em>36</em></code> positions &amp; stops.</p>
+<pre><code>#.....
+</code></pre>
+</article>
+</main></body></html>"#;
+
+    /// Check the case called `name` for `day` of `year`. Called by the tests that `build.rs`
+    /// generates for every case.
+    fn check_case(year: u16, day: u8, name: &str) {
+        let solution = get_solution(year, day)
+            .unwrap_or_else(|| panic!("Day {} of {} is not solved yet", day, year));
+        let cases = SampleCases::load(Path::new(&cases_path(year, day))).unwrap();
+        let case = cases.get(name).unwrap();
+        if let Err(err) = case.check(solution) {
+            panic!("{:#}", err);
+        }
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/sample_cases.rs"));
+
+    #[test]
+    fn parse_puzzle_page() {
+        let sections = parse_page(PAGE);
+        assert_eq!(sections.len(), 2);
+        let texts: Vec<&str> = sections[0].blocks.iter().map(|b| b.text.as_str()).collect();
+        assert_eq!(texts, vec!["R 4\nU 4\n", "......\nH.....\n"]);
+        assert!(sections[0].blocks[0].is_example);
+        assert!(!sections[0].blocks[1].is_example);
+        assert_eq!(sections[0].answer.as_ref().unwrap().1, "13");
+        assert_eq!(sections[1].answer.as_ref().unwrap().1, "36");
+    }
+
+    #[test]
+    fn find_samples_for_each_part() {
+        let samples = find_samples(2022, 9, &parse_page(PAGE)).unwrap();
+        let expected = vec![
+            Sample {
+                file: "input/day09_sample.txt".to_owned(),
+                text: "R 4\nU 4\n".to_owned(),
+                answers: vec![(Part::One, "13".to_owned())],
+            },
+            Sample {
+                file: "input/day09_sample_2.txt".to_owned(),
+                text: "R 5\nU 8\n".to_owned(),
+                answers: vec![(Part::Two, "36".to_owned())],
+            },
+        ];
+        assert_eq!(samples, expected);
+
+        let page = "<pre><code>1\n2</code></pre><p>gives <em><code>3</code></em></p>";
+        let samples = find_samples(2021, 1, &parse_page(page)).unwrap();
+        assert_eq!(samples[0].file, "input/2021/day01_sample.txt");
+        assert_eq!(samples[0].text, "1\n2\n");
+        assert_eq!(samples[0].answers, vec![(Part::One, "3".to_owned())]);
+        assert!(find_samples(2022, 1, &parse_page("<p>No code</p>")).is_err());
+    }
+
+    #[test]
+    fn parse_sample_cases() {
+        let text = "\
+# comment
+== sample
+file: input/day06_sample.txt
+part 1: 7
+part 2: 19
+
+== drawing
+part 2: #.\\n.#
+input:
+    [D]
+
+move 1 from 2 to 1
+
+
+";
+        let cases = SampleCases::parse(text).unwrap();
+        let expected = SampleCases(vec![
+            SampleCase {
+                name: "sample".to_owned(),
+                input: CaseInput::File("input/day06_sample.txt".to_owned()),
+                answers: vec![(Part::One, "7".to_owned()), (Part::Two, "19".to_owned())],
+            },
+            SampleCase {
+                name: "drawing".to_owned(),
+                input: CaseInput::Inline(vec![
+                    "    [D]".to_owned(),
+                    String::new(),
+                    "move 1 from 2 to 1".to_owned(),
+                ]),
+                answers: vec![(Part::Two, "#.\n.#".to_owned())],
+            },
+        ]);
+        assert_eq!(cases, expected);
+        assert_eq!(SampleCases::parse(&cases.to_string()).unwrap(), cases);
+    }
+
+    #[test]
+    fn parse_bad_sample_cases() {
+        assert!(SampleCases::parse("file: input/day06_sample.txt").is_err());
+        assert!(SampleCases::parse("== no input\npart 1: 7").is_err());
+        assert!(SampleCases::parse("== a\npart 3: 7\ninput:\n1").is_err());
+        assert!(SampleCases::parse("== a\nfile: a.txt\n== a\nfile: b.txt").is_err());
+        assert!(SampleCases::parse("== a\nfile a.txt").is_err());
+    }
+
+    #[test]
+    fn check_reports_wrong_answers() {
+        let day06 = get_solution(2022, 6).unwrap();
+        let case = SampleCase {
+            name: "wrong".to_owned(),
+            input: CaseInput::Inline(vec!["bvwbjplbgvbhsrlpgdmjqwftvncz".to_owned()]),
+            answers: vec![(Part::One, "5".to_owned()), (Part::Two, "24".to_owned())],
+        };
+        let err = case.check(day06).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Part 2 of case 'wrong': expected \"24\" but got \"23\""
+        );
+    }
+
+    #[test]
+    fn import_does_not_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-samples-{}", std::process::id()));
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("input/day09_sample.txt"), "edited\n").unwrap();
+        assert!(import_samples(&root, 2022, 9, PAGE, false).is_err());
+        assert!(!root.join("input/day09_cases.txt").exists());
+
+        import_samples(&root, 2022, 9, PAGE, true).unwrap();
+        let sample = fs::read_to_string(root.join("input/day09_sample_2.txt")).unwrap();
+        assert_eq!(sample, "R 5\nU 8\n");
+        let cases = SampleCases::load(&root.join("input/day09_cases.txt")).unwrap();
+        let names: Vec<&str> = cases.0.iter().map(|case| case.name.as_str()).collect();
+        assert_eq!(names, vec!["sample", "sample_2"]);
+        // The same page again changes nothing
+        import_samples(&root, 2022, 9, PAGE, false).unwrap();
+        assert_eq!(
+            SampleCases::load(&root.join("input/day09_cases.txt")).unwrap(),
+            cases
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn every_day_has_sample_cases() {
+        for solution in SOLUTIONS {
+            let path = cases_path(solution.year(), solution.day());
+            let cases = SampleCases::load(Path::new(&path)).unwrap();
+            assert!(!cases.0.is_empty(), "{} has no cases", path);
+        }
+    }
+}
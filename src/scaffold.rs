@@ -0,0 +1,290 @@
+use crate::samples::{cases_path, sample_path};
+use crate::DEFAULT_YEAR;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The template every new day starts from
+pub const TEMPLATE: &str = include_str!("../template.rs");
+
+/// The files that were created for a new day
+#[derive(Debug)]
+pub struct NewDay {
+    pub module: PathBuf,
+    /// `None` if the sample file already existed
+    pub sample: Option<PathBuf>,
+    /// The sample cases, or `None` if they already existed
+    pub cases: Option<PathBuf>,
+}
+
+/// The module of `day` of `year` in `src/days`: `dayXX` for [`DEFAULT_YEAR`], and `y<year>_dayXX`
+/// for other years, like the sample case tests
+pub fn module_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{:02}", day)
+    } else {
+        format!("y{}_day{:02}", year, day)
+    }
+}
+
+/// The year and day of a module named by [`module_name`]
+fn parse_module_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = match name.strip_prefix('y') {
+        Some(rest) => {
+            let (year, day) = rest.split_once("_day")?;
+            (year.parse().ok()?, day)
+        }
+        None => (DEFAULT_YEAR, name.strip_prefix("day")?),
+    };
+    Some((year, day.parse().ok()?))
+}
+
+/// Fill in the placeholders of [`TEMPLATE`] for `day` of `year`. Days of other years than
+/// [`DEFAULT_YEAR`] also get a `year` method.
+pub fn render_template(year: u16, day: u8, title: &str) -> String {
+    let rendered = TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{XX}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE}}", &title.escape_debug().to_string());
+    if year == DEFAULT_YEAR {
+        return rendered;
+    }
+    let day_method = "    fn day(&self) -> u8 {";
+    let year_method = format!("    fn year(&self) -> u16 {{\n        {}\n    }}\n\n", year);
+    rendered.replacen(day_method, &(year_method + day_method), 1)
+}
+
+/// Add `day` of `year` to the module declarations and the `SOLUTIONS` registry in `registry`,
+/// which is the content of `src/days/mod.rs`. Both lists are kept sorted by year and day.
+pub fn add_to_registry(registry: &str, year: u16, day: u8) -> Result<String> {
+    let module = module_name(year, day);
+    let module_line = format!("pub mod {};", module);
+    let solution_line = format!("    &{}::Day{:02},", module, day);
+    let mut lines: Vec<String> = registry.lines().map(str::to_owned).collect();
+    if lines.contains(&module_line) {
+        bail!("Day {} of {} is already in the registry", day, year);
+    }
+    let module_of = |line: &str| {
+        let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+        parse_module_name(name)
+    };
+    let solution_of = |line: &str| {
+        let path = line.trim().strip_prefix('&')?;
+        parse_module_name(path.split("::").next()?)
+    };
+    insert_sorted(
+        &mut lines,
+        (year, day),
+        module_line,
+        "module declarations",
+        module_of,
+    )?;
+    insert_sorted(
+        &mut lines,
+        (year, day),
+        solution_line,
+        "solutions",
+        solution_of,
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Insert `new_line` for `puzzle` among the lines that `puzzle_of` finds a year and day in,
+/// keeping those lines sorted
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    puzzle: (u16, u8),
+    new_line: String,
+    what: &str,
+    puzzle_of: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Result<()> {
+    let matching: Vec<(usize, (u16, u8))> = (0..lines.len())
+        .filter_map(|idx| Some((idx, puzzle_of(&lines[idx])?)))
+        .collect();
+    let (last, _) = *matching
+        .last()
+        .with_context(|| format!("Could not find the {} in the registry", what))?;
+    let idx = matching
+        .into_iter()
+        .find(|&(_, other)| other > puzzle)
+        .map_or(last + 1, |(idx, _)| idx);
+    lines.insert(idx, new_line);
+    Ok(())
+}
+
+/// The sample cases for a new day, with the answers left to fill in
+pub fn render_cases(year: u16, day: u8) -> String {
+    format!(
+        "== sample\nfile: {}\n# part 1: <answer>\n# part 2: <answer>\n",
+        sample_path(year, day, 0)
+    )
+}
+
+/// The source file of `day` of `year`, relative to the root of the crate
+pub fn module_path(year: u16, day: u8) -> String {
+    format!("src/days/{}.rs", module_name(year, day))
+}
+
+/// Create the module, sample input, sample cases and registry entry for `day` of `year`, in the
+/// crate at `root`.
+/// Refuses to touch anything if the day already exists.
+pub fn create_day(root: &Path, year: u16, day: u8, title: &str) -> Result<NewDay> {
+    let module = root.join(module_path(year, day));
+    if module.exists() {
+        bail!(
+            "Day {} of {} already exists in {}",
+            day,
+            year,
+            module.display()
+        );
+    }
+    let registry_path = root.join("src/days/mod.rs");
+    let registry = fs::read_to_string(&registry_path)
+        .with_context(|| format!("Could not read {}", registry_path.display()))?;
+    let registry = add_to_registry(&registry, year, day)?;
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&module)
+        .with_context(|| format!("Could not create {}", module.display()))?;
+    file.write_all(render_template(year, day, title).as_bytes())?;
+    fs::write(&registry_path, registry)?;
+
+    let sample = create_if_missing(root.join(sample_path(year, day, 0)), "")?;
+    let cases = create_if_missing(root.join(cases_path(year, day)), &render_cases(year, day))?;
+    Ok(NewDay {
+        module,
+        sample,
+        cases,
+    })
+}
+
+/// Write `contents` to `path` and return it, unless the file already exists. The directory of a new
+/// year is created too.
+fn create_if_missing(path: PathBuf, contents: &str) -> Result<Option<PathBuf>> {
+    if path.exists() {
+        return Ok(None);
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    fs::write(&path, contents).with_context(|| format!("Could not write {}", path.display()))?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::samples::{CaseInput, SampleCases};
+
+    const REGISTRY: &str = "\
+use crate::Solution;
+
+pub mod day01;
+pub mod day03;
+
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn render_template_for_day() {
+        let rendered = render_template(2022, 7, "No Space Left On Device");
+        assert!(rendered.starts_with("// https://adventofcode.com/2022/day/7\n"));
+        assert!(rendered.contains("pub struct Day07;"));
+        assert!(rendered.contains("\"No Space Left On Device\""));
+        assert!(!rendered.contains("{{"));
+        assert!(!rendered.contains("fn year"));
+
+        let rendered = render_template(2022, 1, r#"A "quoted" \ title"#);
+        assert!(rendered.contains(r#""A \"quoted\" \\ title""#));
+    }
+
+    #[test]
+    fn render_template_for_other_year() {
+        let rendered = render_template(2023, 1, "Trebuchet?!");
+        assert!(rendered.starts_with("// https://adventofcode.com/2023/day/1\n"));
+        assert!(rendered.contains("    fn year(&self) -> u16 {\n        2023\n    }\n\n    fn day"));
+    }
+
+    #[test]
+    fn module_names() {
+        assert_eq!(module_name(2022, 7), "day07");
+        assert_eq!(module_name(2023, 12), "y2023_day12");
+        assert_eq!(parse_module_name("day07"), Some((2022, 7)));
+        assert_eq!(parse_module_name("y2023_day12"), Some((2023, 12)));
+        assert_eq!(parse_module_name("tests"), None);
+    }
+
+    #[test]
+    fn add_day_in_the_middle_of_registry() {
+        let registry = add_to_registry(REGISTRY, 2022, 2).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains("&day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n"));
+    }
+
+    #[test]
+    fn add_day_at_the_end_of_registry() {
+        let registry = add_to_registry(REGISTRY, 2022, 11).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day11;\n"));
+        assert!(registry.contains("&day03::Day03,\n    &day11::Day11,\n];"));
+    }
+
+    #[test]
+    fn add_days_of_other_years_to_registry() {
+        let registry = add_to_registry(REGISTRY, 2023, 1).unwrap();
+        let registry = add_to_registry(&registry, 2021, 25).unwrap();
+        assert!(registry.contains(
+            "pub mod y2021_day25;\npub mod day01;\npub mod day03;\npub mod y2023_day01;\n"
+        ));
+        assert!(registry.contains(
+            "    &y2021_day25::Day25,\n    &day01::Day01,\n    &day03::Day03,\n    &y2023_day01::Day01,\n];"
+        ));
+        assert!(add_to_registry(&registry, 2023, 1).is_err());
+    }
+
+    #[test]
+    fn refuse_to_add_existing_day() {
+        assert!(add_to_registry(REGISTRY, 2022, 3).is_err());
+    }
+
+    #[test]
+    fn create_day_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let new_day = create_day(&root, 2022, 2, "Rock Paper Scissors").unwrap();
+        assert!(new_day.module.exists());
+        assert_eq!(fs::read_to_string(new_day.sample.unwrap()).unwrap(), "");
+        let cases = SampleCases::load(&new_day.cases.unwrap()).unwrap();
+        assert_eq!(
+            cases.0[0].input,
+            CaseInput::File("input/day02_sample.txt".to_owned())
+        );
+        assert!(cases.0[0].answers.is_empty());
+        assert!(create_day(&root, 2022, 2, "Rock Paper Scissors").is_err());
+
+        let new_day = create_day(&root, 2023, 2, "Cube Conundrum").unwrap();
+        assert_eq!(new_day.module, root.join("src/days/y2023_day02.rs"));
+        assert_eq!(
+            new_day.sample,
+            Some(root.join("input/2023/day02_sample.txt"))
+        );
+        let cases = SampleCases::load(&new_day.cases.unwrap()).unwrap();
+        assert_eq!(
+            cases.0[0].input,
+            CaseInput::File("input/2023/day02_sample.txt".to_owned())
+        );
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("    &day03::Day03,\n    &y2023_day02::Day02,\n"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
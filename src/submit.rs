@@ -0,0 +1,419 @@
+//! Submitting answers, and remembering what the website said about them
+use crate::client::Client;
+use crate::html::to_text;
+use crate::runner::Part;
+use crate::Answer;
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Every submitted answer and the reply to it. Not checked in, since it is only about your inputs.
+pub const SUBMISSIONS_FILE: &str = "submissions.txt";
+
+/// How long to wait after a wrong answer. The website asks for at least this, and more after
+/// several wrong answers, which it then tells us about with [`Outcome::Wait`].
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the website said about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way
+    Wrong,
+    /// An answer was submitted too recently, so this one was not checked
+    Wait(Duration),
+    /// The part is already solved, so this answer was not checked
+    AlreadySolved,
+    /// A reply we don't recognize, with its text
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Whether the answer was checked and is wrong
+    pub fn is_rejected(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    /// How long to wait before submitting again
+    pub fn cooldown(&self) -> Duration {
+        match self {
+            Outcome::Correct | Outcome::AlreadySolved => Duration::ZERO,
+            Outcome::Wait(wait) => *wait,
+            _ => COOLDOWN,
+        }
+    }
+
+    /// How the outcome is written in [`SUBMISSIONS_FILE`]
+    fn code(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_owned(),
+            Outcome::TooHigh => "too-high".to_owned(),
+            Outcome::TooLow => "too-low".to_owned(),
+            Outcome::Wrong => "wrong".to_owned(),
+            Outcome::Wait(wait) => format!("wait-{}", wait.as_secs()),
+            Outcome::AlreadySolved => "already-solved".to_owned(),
+            Outcome::Unknown(_) => "unknown".to_owned(),
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Outcome> {
+        Some(match code {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "already-solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown(String::new()),
+            _ => Outcome::Wait(Duration::from_secs(
+                code.strip_prefix("wait-")?.parse().ok()?,
+            )),
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Outcome::TooLow => write!(f, "Wrong, the answer is too low"),
+            Outcome::Wrong => write!(f, "Wrong"),
+            Outcome::Wait(wait) => write!(
+                f,
+                "Not checked, wait {} before submitting again",
+                format_wait(*wait)
+            ),
+            Outcome::AlreadySolved => write!(f, "Not checked, this part is already solved"),
+            Outcome::Unknown(text) => write!(f, "Unknown reply: {}", text),
+        }
+    }
+}
+
+/// Read the outcome from the page the website replies with
+pub fn classify(page: &str) -> Outcome {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Wait(parse_wait(&text).unwrap_or(COOLDOWN))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// The text of the `<article>` in `page`, or of all of it if there is none, on a single line
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    to_text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Read a wait like "You have 1m 23s left to wait"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let unit = amount.chars().last()?;
+        let number: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+        seconds += match unit {
+            'h' => number * 3600,
+            'm' => number * 60,
+            's' => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{}s", seconds),
+        (minutes, 0) => format!("{}m", minutes),
+        (minutes, seconds) => format!("{}m {}s", minutes, seconds),
+    }
+}
+
+/// One submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// When it was submitted, in seconds since the Unix epoch
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, oldest first
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History(Vec<Submission>);
+
+impl History {
+    /// Parse lines of `<time> <year> <day> <part> <outcome> <answer>`
+    pub fn parse(text: &str) -> Result<History> {
+        let mut submissions = Vec::new();
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let context = || format!("Bad submission on line {}: '{}'", idx + 1, line);
+            let fields: Vec<&str> = line.splitn(6, ' ').collect();
+            let [time, year, day, part, outcome, answer] = fields[..] else {
+                bail!(context());
+            };
+            submissions.push(Submission {
+                time: time.parse().with_context(context)?,
+                year: year.parse().with_context(context)?,
+                day: day.parse().with_context(context)?,
+                part: part.parse().with_context(context)?,
+                answer: answer.to_owned(),
+                outcome: Outcome::from_code(outcome).with_context(context)?,
+            });
+        }
+        Ok(History(submissions))
+    }
+
+    /// Load the history from `path`, which is empty if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<History> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        History::parse(&text)
+    }
+
+    /// Add `submission` to the end of the file at `path`
+    pub fn append(path: &Path, submission: &Submission) -> Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Could not open {}", path.display()))?;
+        writeln!(
+            file,
+            "{} {} {:02} {} {} {}",
+            submission.time,
+            submission.year,
+            submission.day,
+            submission.part,
+            submission.outcome.code(),
+            submission.answer
+        )?;
+        Ok(())
+    }
+
+    /// Check that `answer` is worth submitting at `now`: the cooldown is over, the part is not
+    /// solved yet, and the answer has not been rejected, directly or by a too high or too low
+    /// answer it is beyond
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Result<()> {
+        if let Some(last) = self.0.last() {
+            let ready = last.time + last.outcome.cooldown().as_secs();
+            if now < ready {
+                let wait = Duration::from_secs(ready - now);
+                bail!(
+                    "Wait {} before submitting again, the last answer was {}",
+                    format_wait(wait),
+                    last.outcome.code()
+                );
+            }
+        }
+        let number = answer.parse::<i64>().ok();
+        let earlier_submissions = self
+            .0
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+        for submission in earlier_submissions {
+            let earlier = submission.answer.parse::<i64>().ok();
+            let beyond = match (&submission.outcome, number, earlier) {
+                (Outcome::TooHigh, Some(number), Some(earlier)) => number >= earlier,
+                (Outcome::TooLow, Some(number), Some(earlier)) => number <= earlier,
+                _ => false,
+            };
+            if submission.outcome == Outcome::Correct {
+                bail!(
+                    "Day {} part {} is already solved, with {}",
+                    day,
+                    part,
+                    submission.answer
+                );
+            } else if submission.outcome.is_rejected() && submission.answer == answer {
+                bail!(
+                    "{} was already rejected as {}",
+                    answer,
+                    submission.outcome.code()
+                );
+            } else if beyond {
+                bail!(
+                    "{} can't be right, since {} was {}",
+                    answer,
+                    submission.answer,
+                    submission.outcome.code()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submit `answer` for `part` of `day` of `year`, unless [`History::check`] says not to, and record the
+/// outcome in the history file at `path`
+pub fn submit_answer(
+    client: &Client,
+    path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: SystemTime,
+) -> Result<Outcome> {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        bail!("The answer is a drawing, read the letters in it and submit them by hand");
+    }
+    let time = now.duration_since(UNIX_EPOCH)?.as_secs();
+    History::load(path)?.check(year, day, part, &answer, time)?;
+    let outcome = classify(&client.submit(year, day, part, &answer)?);
+    let submission = Submission {
+        time,
+        year,
+        day,
+        part,
+        answer,
+        outcome: outcome.clone(),
+    };
+    History::append(path, &submission)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::stub_server;
+    use crate::client::ClientConfig;
+    use std::env;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn classify_replies() {
+        let correct = page("That's the right answer! You are <em>one gold star</em> closer.");
+        assert_eq!(classify(&correct), Outcome::Correct);
+        let too_high = page("That's not the right answer; your answer is too high. Please wait one minute before trying again.");
+        assert_eq!(classify(&too_high), Outcome::TooHigh);
+        let too_low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(classify(&too_low), Outcome::TooLow);
+        let wrong = page("That's not the right answer. If you're stuck, make sure you're using the full input data.");
+        assert_eq!(classify(&wrong), Outcome::Wrong);
+        let wait = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.");
+        assert_eq!(classify(&wait), Outcome::Wait(Duration::from_secs(83)));
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(classify(&solved), Outcome::AlreadySolved);
+        assert_eq!(
+            classify(&page("Something <b>new</b>")),
+            Outcome::Unknown("Something new".to_owned())
+        );
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let text = "1000 2022 05 1 too-high CMZ\n1100 2022 05 1 wait-42 MCD\n1200 2021 10 2 correct 1 2 3\n";
+        let history = History::parse(text).unwrap();
+        assert_eq!(history.0[1].outcome, Outcome::Wait(Duration::from_secs(42)));
+        assert_eq!(history.0[2].answer, "1 2 3");
+        assert!(History::parse("1000 2022 05 1 maybe CMZ").is_err());
+        assert!(History::parse("1000 2022 05 1 too-high").is_err());
+    }
+
+    #[test]
+    fn check_before_submitting() {
+        let history =
+            History::parse("1000 2022 01 1 too-high 500\n1010 2022 01 1 too-low 100\n").unwrap();
+        assert!(history.check(2022, 1, Part::One, "300", 1030).is_err());
+        assert!(history.check(2022, 1, Part::One, "300", 1070).is_ok());
+        assert!(history.check(2022, 1, Part::One, "500", 2000).is_err());
+        assert!(history.check(2022, 1, Part::One, "600", 2000).is_err());
+        assert!(history.check(2022, 1, Part::One, "99", 2000).is_err());
+        assert!(history.check(2022, 1, Part::Two, "600", 2000).is_ok());
+        assert!(history.check(2021, 1, Part::One, "600", 2000).is_ok());
+
+        let history = History::parse("1000 2022 02 2 correct 42\n").unwrap();
+        assert!(history.check(2022, 2, Part::Two, "42", 1001).is_err());
+        assert!(history.check(2022, 2, Part::One, "42", 1001).is_ok());
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}.txt", std::process::id()));
+        let replies =
+            vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ];
+        let (base_url, server) = stub_server(replies);
+        let client = Client::new(ClientConfig {
+            session: Some("abc123".to_owned()),
+            base_url,
+        });
+        let start = UNIX_EPOCH + Duration::from_secs(1000);
+
+        let outcome =
+            submit_answer(&client, &path, 2022, 4, Part::Two, &Answer::Int(10), start).unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+        let during_cooldown = start + Duration::from_secs(30);
+        assert!(submit_answer(
+            &client,
+            &path,
+            2022,
+            4,
+            Part::Two,
+            &Answer::Int(20),
+            during_cooldown
+        )
+        .is_err());
+        let later = start + COOLDOWN;
+        assert!(submit_answer(&client, &path, 2022, 4, Part::Two, &Answer::Int(5), later).is_err());
+        let outcome =
+            submit_answer(&client, &path, 2022, 4, Part::Two, &Answer::Int(20), later).unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2022/day/4/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=10"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "1000 2022 04 2 too-low 10\n1060 2022 04 2 correct 20\n"
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+use crate::runner::{locate_error, run_part, Input, Part};
+use crate::{Solution, DEFAULT_YEAR};
+use anyhow::{bail, Context, Result};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Where the accepted answers are checked in
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Accepted answers for the real inputs, by year, day and part
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers(BTreeMap<(u16, u8, Part), String>);
+
+impl KnownAnswers {
+    /// Parse answers from lines of `[year] <day> <part> <answer>`, where the year is
+    /// [`DEFAULT_YEAR`] if it is left out. Empty lines and lines starting with `#` are skipped,
+    /// and `\n` in an answer is turned into a line break.
+    pub fn parse(text: &str) -> Result<KnownAnswers> {
+        let mut answers = BTreeMap::new();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let context = || format!("Bad answer on line {}: '{}'", idx + 1, line);
+            let (first, rest) = line.split_once(' ').with_context(context)?;
+            let first: u16 = first.parse().with_context(context)?;
+            // Days only go up to 25, so anything larger is a year
+            let (year, day, rest) = if first > 25 {
+                let (day, rest) = rest.split_once(' ').with_context(context)?;
+                (first, day.parse().with_context(context)?, rest)
+            } else {
+                (DEFAULT_YEAR, first as u8, rest)
+            };
+            let (part, answer) = rest.split_once(' ').with_context(context)?;
+            let part: Part = part.parse().with_context(context)?;
+            let answer = answer.trim().replace("\\n", "\n");
+            if answers.insert((year, day, part), answer).is_some() {
+                bail!(
+                    "{} day {} part {} has more than one answer",
+                    year,
+                    day,
+                    part
+                );
+            }
+        }
+        Ok(KnownAnswers(answers))
+    }
+
+    pub fn load(filename: &str) -> Result<KnownAnswers> {
+        let text = fs::read_to_string(filename)
+            .with_context(|| format!("Could not read answers from {}", filename))?;
+        KnownAnswers::parse(&text)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+/// The outcome of checking one part against its known answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+        actual: String,
+    },
+    /// There is no known answer to compare with
+    Missing {
+        actual: String,
+    },
+    /// The solution returned an error
+    Failed(String),
+    Panicked(String),
+}
+
+impl Verdict {
+    /// Whether this should fail the verification. A missing answer is only a warning.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Correct | Verdict::Missing { .. })
+    }
+}
+
+#[derive(Debug)]
+pub struct PartVerdict {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for PartVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year != DEFAULT_YEAR {
+            write!(f, "{} ", self.year)?;
+        }
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match &self.verdict {
+            Verdict::Correct => write!(f, "ok"),
+            Verdict::Wrong { expected, actual } => {
+                write!(f, "WRONG, expected {:?} but got {:?}", expected, actual)
+            }
+            Verdict::Missing { actual } => write!(f, "no known answer, got {:?}", actual),
+            Verdict::Failed(err) => write!(f, "FAILED: {}", err),
+            Verdict::Panicked(message) => write!(f, "PANICKED: {}", message),
+        }
+    }
+}
+
+/// Run both parts of every solution on its real input, and compare with `answers`
+pub fn verify(solutions: &[&dyn Solution], answers: &KnownAnswers) -> Vec<PartVerdict> {
+    let mut verdicts = Vec::new();
+    for solution in solutions {
+        let input = Input::Default.read(*solution);
+        for part in Part::BOTH {
+            let verdict = match &input {
+                Ok(input) => check_part(*solution, part, input, answers),
+                Err(err) => Verdict::Failed(format!("{:#}", err)),
+            };
+            verdicts.push(PartVerdict {
+                year: solution.year(),
+                day: solution.day(),
+                part,
+                verdict,
+            });
+        }
+    }
+    verdicts
+}
+
+fn check_part(
+    solution: &dyn Solution,
+    part: Part,
+    input: &[String],
+    answers: &KnownAnswers,
+) -> Verdict {
+    match catch_panic(|| run_part(solution, part, input)) {
+        Ok(Ok(answer)) => {
+            let actual = answer.to_string();
+            match answers.get(solution.year(), solution.day(), part) {
+                Some(expected) if expected == actual => Verdict::Correct,
+                Some(expected) => Verdict::Wrong {
+                    expected: expected.to_owned(),
+                    actual,
+                },
+                None => Verdict::Missing { actual },
+            }
+        }
+        Ok(Err(err)) => {
+            let err = locate_error(err, &solution.input_path());
+            Verdict::Failed(format!("{:#}", err))
+        }
+        Err(message) => Verdict::Panicked(message),
+    }
+}
+
+/// Run `f`, turning a panic into an `Err` with the panic message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::SOLUTIONS;
+
+    #[test]
+    fn parse_answers() {
+        let text = "# comment\n\n01 1 24000\n5 2 MCD\n10 2 #.\\n.#\n2021 01 1 1400\n";
+        let answers = KnownAnswers::parse(text).unwrap();
+        assert_eq!(answers.get(2022, 1, Part::One), Some("24000"));
+        assert_eq!(answers.get(2022, 5, Part::Two), Some("MCD"));
+        assert_eq!(answers.get(2022, 10, Part::Two), Some("#.\n.#"));
+        assert_eq!(answers.get(2022, 1, Part::Two), None);
+        assert_eq!(answers.get(2021, 1, Part::One), Some("1400"));
+    }
+
+    #[test]
+    fn parse_bad_answers() {
+        assert!(KnownAnswers::parse("01 1").is_err());
+        assert!(KnownAnswers::parse("01 3 24000").is_err());
+        assert!(KnownAnswers::parse("01 1 24000\n1 1 45000").is_err());
+        assert!(KnownAnswers::parse("01 1 24000\n2022 1 1 45000").is_err());
+        assert!(KnownAnswers::parse("2022 1").is_err());
+    }
+
+    #[test]
+    fn catch_panic_message() {
+        let result = catch_panic(|| -> i32 { panic!("Could not find any overlap") });
+        assert_eq!(result, Err("Could not find any overlap".to_owned()));
+        assert_eq!(catch_panic(|| 1), Ok(1));
+    }
+
+    #[test]
+    fn known_answers_match() {
+        let answers = KnownAnswers::load(ANSWERS_FILE).unwrap();
+        for verdict in verify(SOLUTIONS, &answers) {
+            assert_eq!(verdict.verdict, Verdict::Correct, "{}", verdict);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Showing the days that are simulations one step at a time in the terminal
+use crate::grid::Pos;
+use crate::runner::Part;
+use crate::{Grid, Solution};
+use anyhow::{Context, Result};
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// A simulation that can be drawn after every step, see [`Solution::visualize`]
+pub trait Visualize {
+    /// Draw the current state
+    fn frame(&self) -> Frame;
+
+    /// Move on to the next state. Returns `false` when the simulation is already done.
+    fn step(&mut self) -> Result<bool>;
+}
+
+/// How a cell is drawn, from least to most eye-catching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Style {
+    #[default]
+    Empty,
+    Dim,
+    Normal,
+    Bright,
+    /// What changed in the last step
+    Highlight,
+}
+
+impl Style {
+    /// The ANSI escape code that starts this style
+    fn ansi(self) -> &'static str {
+        match self {
+            Style::Empty | Style::Normal => "\x1b[0m",
+            Style::Dim => "\x1b[0;90m",
+            Style::Bright => "\x1b[0;1;32m",
+            Style::Highlight => "\x1b[0;1;30;43m",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub symbol: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const EMPTY: Cell = Cell {
+        symbol: ' ',
+        style: Style::Empty,
+    };
+
+    pub fn new(symbol: char, style: Style) -> Self {
+        Cell { symbol, style }
+    }
+}
+
+/// One picture of a simulation. Every frame of a simulation should be the same size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    /// A line of text under the picture, like the cycle and register of day 10
+    pub caption: String,
+    /// The cell to keep in view when the picture doesn't fit in the terminal
+    pub focus: Option<Pos>,
+}
+
+impl Frame {
+    /// An empty `width` by `height` frame
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame {
+            cells: Grid::new(width, height, Cell::EMPTY),
+            caption: String::new(),
+            focus: None,
+        }
+    }
+
+    /// Draw `text` from `pos` to the right, cutting it off at the edge
+    pub fn write(&mut self, (row, column): Pos, text: &str, style: Style) {
+        for (offset, symbol) in text.chars().enumerate() {
+            self.cells
+                .set((row, column + offset), Cell::new(symbol, style));
+        }
+    }
+
+    /// The lines of text for the part of the frame that fits in `width` by `height` cells, with
+    /// ANSI colours
+    pub fn render(&self, width: usize, height: usize) -> String {
+        let focus = self.focus.unwrap_or((0, 0));
+        let rows = window(focus.0, height, self.cells.height());
+        let columns = window(focus.1, width, self.cells.width());
+        let mut text = String::new();
+        for row in rows {
+            let mut style = Style::Empty;
+            for &cell in &self.cells.row(row)[columns.clone()] {
+                if cell.style != style {
+                    text.push_str(cell.style.ansi());
+                    style = cell.style;
+                }
+                text.push(cell.symbol);
+            }
+            if style != Style::Empty {
+                text.push_str(Style::Empty.ansi());
+            }
+            text.push('\n');
+        }
+        text.push_str(&self.caption);
+        text
+    }
+}
+
+/// The `size` indices out of `0..len` that show `focus` as close to the middle as they can
+pub fn window(focus: usize, size: usize, len: usize) -> Range<usize> {
+    let size = size.min(len);
+    let start = focus.saturating_sub(size / 2).min(len - size);
+    start..start + size
+}
+
+/// How to play a simulation in the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayConfig {
+    /// How long each frame is shown
+    pub delay: Duration,
+    /// Start paused, waiting for a key before every step
+    pub paused: bool,
+}
+
+impl Default for PlayConfig {
+    fn default() -> Self {
+        PlayConfig {
+            delay: Duration::from_millis(100),
+            paused: false,
+        }
+    }
+}
+
+/// What to do after a line typed while playing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// Pause, and show the next step. Just Enter.
+    Step,
+    /// Play on from here
+    Continue,
+    Quit,
+}
+
+impl Key {
+    pub fn parse(line: &str) -> Key {
+        match line.trim() {
+            "c" => Key::Continue,
+            "q" => Key::Quit,
+            _ => Key::Step,
+        }
+    }
+}
+
+const HELP: &str = "Enter: pause and step, c Enter: play, q Enter: quit";
+
+/// The size of the terminal from `COLUMNS` and `LINES`, or 80 by 24 if they are not set
+fn terminal_size() -> (usize, usize) {
+    let size = |name, default| {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    };
+    (size("COLUMNS", 80), size("LINES", 24))
+}
+
+/// Show every frame of `simulation` on `out`, clearing the screen in between, and wait for `keys`
+/// to step through it. Returns `false` if it was stopped with [`Key::Quit`].
+pub fn play(
+    simulation: &mut dyn Visualize,
+    config: PlayConfig,
+    keys: &Receiver<Key>,
+    out: &mut impl Write,
+) -> Result<bool> {
+    let (width, height) = terminal_size();
+    let mut paused = config.paused;
+    loop {
+        // Leave room for the caption and the help
+        let picture = simulation
+            .frame()
+            .render(width, height.saturating_sub(3).max(1));
+        write!(out, "\x1b[2J\x1b[H{}\n{}\n", picture, HELP)
+            .and_then(|_| out.flush())
+            .context("Could not write to stdout")?;
+        let key = if paused {
+            keys.recv().ok()
+        } else {
+            match keys.recv_timeout(config.delay) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                // No more keys can come, so just keep playing at the same speed
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(config.delay);
+                    None
+                }
+            }
+        };
+        match key {
+            Some(Key::Step) => paused = true,
+            Some(Key::Continue) => paused = false,
+            Some(Key::Quit) => return Ok(false),
+            None => paused = false,
+        }
+        if !simulation.step()? {
+            return Ok(true);
+        }
+    }
+}
+
+/// The simulation of `part` of `solution`, or an error if it doesn't have one
+pub fn simulation(
+    solution: &dyn Solution,
+    part: Part,
+    input: &[String],
+) -> Result<Box<dyn Visualize>> {
+    solution
+        .visualize(part, input)
+        .with_context(|| format!("Day {} part {} can not be visualized", solution.day(), part))?
+}
+
+/// Play each of `parts` of `solution` on `input` in the terminal, reading keys from stdin
+pub fn visualize_day(
+    solution: &dyn Solution,
+    parts: &[Part],
+    input: &[String],
+    config: PlayConfig,
+) -> Result<()> {
+    let (sender, keys) = mpsc::channel();
+    // This thread is left blocked on stdin when the last part is done
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines().map_while(Result::ok) {
+            if sender.send(Key::parse(&line)).is_err() {
+                break;
+            }
+        }
+    });
+    for &part in parts {
+        let mut simulation = simulation(solution, part, input)?;
+        if !play(simulation.as_mut(), config, &keys, &mut io::stdout().lock())? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::SOLUTIONS;
+    use crate::file_to_vec;
+
+    /// Counts up to a number, drawing the count
+    struct Counter {
+        count: usize,
+        last: usize,
+    }
+
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            let mut frame = Frame::new(3, 1);
+            frame.write((0, 0), &self.count.to_string(), Style::Highlight);
+            frame.caption = format!("Count {}", self.count);
+            frame
+        }
+
+        fn step(&mut self) -> Result<bool> {
+            self.count += 1;
+            Ok(self.count <= self.last)
+        }
+    }
+
+    #[test]
+    fn render_with_colours() {
+        let mut frame = Frame::new(4, 2);
+        frame.write((0, 1), "ab", Style::Bright);
+        frame.write((1, 2), "cdef", Style::Dim);
+        frame.caption = "Done".to_owned();
+        assert_eq!(
+            frame.render(80, 24),
+            " \x1b[0;1;32mab\x1b[0m \n  \x1b[0;90mcd\x1b[0m\nDone"
+        );
+    }
+
+    #[test]
+    fn render_around_focus() {
+        let mut frame = Frame::new(10, 1);
+        frame.write((0, 0), "0123456789", Style::Normal);
+        frame.focus = Some((0, 5));
+        assert!(frame.render(4, 1).starts_with("\x1b[0m3456\x1b[0m\n"));
+        frame.focus = Some((0, 9));
+        assert!(frame.render(4, 1).starts_with("\x1b[0m6789\x1b[0m\n"));
+        assert_eq!(window(0, 4, 10), 0..4);
+    }
+
+    #[test]
+    fn play_until_quit() {
+        let config = PlayConfig {
+            delay: Duration::ZERO,
+            paused: false,
+        };
+        let (sender, keys) = mpsc::channel();
+        drop(sender);
+        let mut out = Vec::new();
+        let mut counter = Counter { count: 0, last: 2 };
+        assert!(play(&mut counter, config, &keys, &mut out).unwrap());
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[2J").count(), 3);
+        assert!(out.contains("Count 2"));
+
+        let (sender, keys) = mpsc::channel();
+        sender.send(Key::Step).unwrap();
+        sender.send(Key::parse("q\n")).unwrap();
+        let mut out = Vec::new();
+        let mut counter = Counter { count: 0, last: 5 };
+        assert!(!play(&mut counter, config, &keys, &mut out).unwrap());
+        assert_eq!(counter.count, 1);
+    }
+
+    #[test]
+    fn simulations_keep_the_same_size() {
+        for solution in SOLUTIONS {
+            let sample = format!("input/day{:02}_sample.txt", solution.day());
+            let input = file_to_vec(&sample).unwrap();
+            for part in Part::BOTH {
+                let Some(simulation) = solution.visualize(part, &input) else {
+                    continue;
+                };
+                let mut simulation = simulation.unwrap();
+                let first = simulation.frame();
+                let mut n_frames = 1;
+                while simulation.step().unwrap() {
+                    let frame = simulation.frame();
+                    assert_eq!(frame.cells.width(), first.cells.width());
+                    assert_eq!(frame.cells.height(), first.cells.height());
+                    n_frames += 1;
+                }
+                assert!(n_frames > 1, "day {} part {}", solution.day(), part);
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Re-running a day whenever its input, samples or source change
+use crate::days::get_solution;
+use crate::report::{run_day, write_results, Format};
+use crate::runner::{Input, Part};
+use crate::scaffold::{module_name, module_path};
+use crate::DEFAULT_YEAR;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often to look for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Notices when any of the files for a day change, appear or disappear
+pub struct Watcher {
+    year: u16,
+    day: u8,
+    root: PathBuf,
+    /// When each file was last modified, as of the last poll, or `None` before the first poll
+    modified: Option<BTreeMap<PathBuf, SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(year: u16, day: u8, root: &Path) -> Self {
+        Watcher {
+            year,
+            day,
+            root: root.to_owned(),
+            modified: None,
+        }
+    }
+
+    /// The source file for the day, which only exists in a checkout of the repository
+    pub fn source(&self) -> PathBuf {
+        self.root.join(module_path(self.year, self.day))
+    }
+
+    /// The input, every sample starting with `dayXX_`, and the source if there is one. They are
+    /// looked for in `input/<year>/`, and also in `input/` for [`DEFAULT_YEAR`].
+    fn files(&self) -> Vec<PathBuf> {
+        let prefix = format!("day{:02}", self.day);
+        let mut dirs = vec![self.root.join("input").join(self.year.to_string())];
+        if self.year == DEFAULT_YEAR {
+            dirs.push(self.root.join("input"));
+        }
+        let mut files: Vec<PathBuf> = dirs
+            .iter()
+            .flat_map(fs::read_dir)
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                name == format!("{}.txt", prefix) || name.starts_with(&format!("{}_", prefix))
+            })
+            .collect();
+        files.push(self.source());
+        files
+    }
+
+    /// Whether anything changed since the last poll. The first poll always counts as a change.
+    pub fn poll(&mut self) -> bool {
+        let modified: BTreeMap<PathBuf, SystemTime> = self
+            .files()
+            .into_iter()
+            .filter_map(|path| {
+                let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+                Some((path, modified))
+            })
+            .collect();
+        self.modified.replace(modified.clone()) != Some(modified)
+    }
+}
+
+/// The outcome of running the tests for a day
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+/// Read which tests passed and failed from the output of `cargo test`
+pub fn parse_test_output(output: &str) -> TestSummary {
+    let mut summary = TestSummary::default();
+    for line in output.lines() {
+        let Some(test) = line.strip_prefix("test ") else {
+            continue;
+        };
+        if test.ends_with(" ... ok") {
+            summary.passed += 1;
+        } else if let Some(name) = test.strip_suffix(" ... FAILED") {
+            let name = name.rsplit("::").next().unwrap_or(name);
+            summary.failed.push(name.to_owned());
+        }
+    }
+    summary
+}
+
+/// Build and run the tests for `day` with cargo, or explain why that failed
+fn run_tests(year: u16, day: u8, root: &Path) -> Result<TestSummary, String> {
+    let module = module_name(year, day);
+    let output = Command::new("cargo")
+        .args(["test", "--lib", "--"])
+        // The unit tests of the day, and the tests for its sample cases
+        .arg(format!("days::{}::", module))
+        .arg(format!("samples::tests::{}::", module))
+        .current_dir(root)
+        .output()
+        .map_err(|err| format!("Could not run cargo: {}", err))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() && !stdout.contains("test result:") {
+        return Err(first_error(&String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(parse_test_output(&stdout))
+}
+
+/// Solve both parts on the real input with a fresh build, since the source may have changed
+fn run_real_input(year: u16, day: u8, root: &Path) -> String {
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--",
+            "run",
+            &year.to_string(),
+            &day.to_string(),
+        ])
+        .current_dir(root)
+        .output();
+    match output {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stdout.is_empty() {
+                first_error(&stderr)
+            } else {
+                // Leave out the heading, the panel has its own
+                let answers: Vec<&str> = stdout.lines().skip(1).collect();
+                answers.join("\n") + "\n" + stderr.trim_end()
+            }
+        }
+        Err(err) => format!("Could not run cargo: {}", err),
+    }
+}
+
+/// The first error in the output of a failed build
+fn first_error(stderr: &str) -> String {
+    stderr
+        .lines()
+        .find(|line| line.starts_with("error"))
+        .unwrap_or("Build failed")
+        .to_owned()
+}
+
+/// Solve the day in this process, when there is no source to rebuild from
+fn run_in_process(year: u16, day: u8) -> String {
+    let Some(solution) = get_solution(year, day) else {
+        return format!("Day {} is not solved yet", day);
+    };
+    let results = run_day(solution, &Part::BOTH, &Input::Default, false);
+    let mut out = Vec::new();
+    write_results(&mut out, Format::Text, &results).expect("writing to a Vec can't fail");
+    let text = String::from_utf8_lossy(&out);
+    text.lines().skip(1).collect::<Vec<_>>().join("\n")
+}
+
+/// A short summary of a run: the tests on top, then the answers
+pub fn panel(
+    year: u16,
+    day: u8,
+    tests: Option<&Result<TestSummary, String>>,
+    answers: &str,
+) -> String {
+    let title = get_solution(year, day).map_or("", |solution| solution.title());
+    let mut panel = format!("{} Day {:02}: {}\n", year, day, title);
+    match tests {
+        Some(Ok(summary)) if summary.failed.is_empty() => {
+            panel += &format!("Tests: PASS ({} passed)\n", summary.passed);
+        }
+        Some(Ok(summary)) => {
+            panel += &format!(
+                "Tests: FAIL ({} passed, {} failed)\n",
+                summary.passed,
+                summary.failed.len()
+            );
+            for name in &summary.failed {
+                panel += &format!("  failed: {}\n", name);
+            }
+        }
+        Some(Err(message)) => panel += &format!("Tests: BUILD FAILED\n  {}\n", message),
+        None => panel += "Tests: skipped, there is no source to build\n",
+    }
+    panel += answers.trim_end();
+    panel.push('\n');
+    panel
+}
+
+/// Re-run `day` of `year` every time its files change, until interrupted
+pub fn watch(year: u16, day: u8, root: &Path) -> Result<()> {
+    let mut watcher = Watcher::new(year, day, root);
+    let has_source = watcher.source().exists() && root.join("Cargo.toml").exists();
+    loop {
+        if watcher.poll() {
+            let (tests, answers) = if has_source {
+                (
+                    Some(run_tests(year, day, root)),
+                    run_real_input(year, day, root),
+                )
+            } else {
+                (None, run_in_process(year, day))
+            };
+            let mut stdout = io::stdout().lock();
+            // Clear the screen and move to the top left
+            write!(
+                stdout,
+                "\x1b[2J\x1b[H{}",
+                panel(year, day, tests.as_ref(), &answers)
+            )
+            .and_then(|_| writeln!(stdout, "\nWatching for changes, press Ctrl-C to stop"))
+            .and_then(|_| stdout.flush())
+            .context("Could not write to stdout")?;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn poll_notices_changes() {
+        let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("input/day05.txt"), "real").unwrap();
+        fs::write(root.join("input/day15.txt"), "other day").unwrap();
+
+        let mut watcher = Watcher::new(2022, 5, &root);
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        fs::write(root.join("input/day05_sample.txt"), "sample").unwrap();
+        assert!(watcher.poll());
+        let past = SystemTime::now() - Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(root.join("input/day05.txt"))
+            .unwrap()
+            .set_modified(past)
+            .unwrap();
+        assert!(watcher.poll());
+        let other = File::options()
+            .write(true)
+            .open(root.join("input/day15.txt"))
+            .unwrap();
+        other.set_modified(past).unwrap();
+        assert!(!watcher.poll());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn parse_cargo_test_output() {
+        let output = "\
+running 3 tests
+test days::day05::tests::parse_stacks ... ok
+test days::day05::tests::part_1_sample_input ... FAILED
+test days::day05::tests::part_2_sample_input ... ok
+
+test result: FAILED. 2 passed; 1 failed; 0 ignored; 0 measured; 129 filtered out
+";
+        let summary = parse_test_output(output);
+        assert_eq!(summary.passed, 2);
+        assert_eq!(summary.failed, vec!["part_1_sample_input".to_owned()]);
+    }
+
+    #[test]
+    fn panel_with_failures() {
+        let summary = TestSummary {
+            passed: 2,
+            failed: vec!["part_1_sample_input".to_owned()],
+        };
+        let expected = "\
+2022 Day 05: Supply Stacks
+Tests: FAIL (2 passed, 1 failed)
+  failed: part_1_sample_input
+Part 1: ZWHVFWQWW
+";
+        assert_eq!(
+            panel(2022, 5, Some(&Ok(summary)), "Part 1: ZWHVFWQWW\n"),
+            expected
+        );
+    }
+}
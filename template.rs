@@ -1,42 +1,32 @@
-use aoc_rust_2022::file_to_vec;
-use anyhow::Result;
+// https://adventofcode.com/{{YEAR}}/day/{{DAY}}
 
-fn main() {
-    let filename = "input/dayXX.txt";
-    let input = file_to_vec(filename).unwrap();
-    let part_1_result = part_1(&input).unwrap();
-    println!("Part 1: {}", part_1_result);
+use crate::{Answer, Solution};
+use anyhow::{bail, Result};
 
-    let part_2_result = part_2(&input).unwrap();
-    println!("Part 2: {}", part_2_result);
-}
+pub struct Day{{XX}};
 
-fn part_1(input: &[String]) -> Result<i32> {
-    todo!()
-}
+impl Solution for Day{{XX}} {
+    fn day(&self) -> u8 {
+        {{DAY}}
+    }
 
-fn part_2(input: &[String]) -> Result<i32> {
-    todo!()
-}
+    fn title(&self) -> &'static str {
+        "{{TITLE}}"
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    // Part 1
-    #[test]
-    fn part_1_sample_input() {
-        let filename = "input/dayXX_sample.txt";
-        let input = file_to_vec(filename).unwrap();
-        let result = part_1(&input).unwrap();
-        assert_eq!(result, 24000);
+    fn part_1(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_1(input)?.into())
     }
 
-    // Part 2
-    #[test]
-    fn part_2_sample_input() {
-        let filename = "input/dayXX_sample.txt";
-        let input = file_to_vec(filename).unwrap();
-        let result = part_2(&input).unwrap();
-        assert_eq!(result, 45000);
+    fn part_2(&self, input: &[String]) -> Result<Answer> {
+        Ok(part_2(input)?.into())
     }
 }
+
+fn part_1(_input: &[String]) -> Result<i32> {
+    bail!("Part 1 is not solved yet")
+}
+
+fn part_2(_input: &[String]) -> Result<i32> {
+    bail!("Part 2 is not solved yet")
+}